@@ -0,0 +1,101 @@
+//! Generates the registry of all solutions for the `solutions` binary, see `template::registry`.
+//!
+//! Every `src/bin/<year>-<day>.rs` is included as a module and its parts are wrapped into `fn(&str) -> Option<String>`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+
+    // every solution declares its own global allocator when profiling, they can't be linked into one binary.
+    let solutions = if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some() {
+        vec![]
+    } else {
+        find_solutions(&Path::new(&manifest_dir).join("src/bin"))
+    };
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for solution in &solutions {
+        let module = format!("solution_{}_{:02}", solution.year, solution.day);
+
+        modules.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code, unused_imports)]\n#[path = {:?}]\nmod {module};\n\n",
+            solution.path
+        ));
+
+        let part = |name: &str, enabled: bool| {
+            if enabled {
+                format!("Some(|input| {module}::{name}(input).map(|x| advent_of_code::template::Answer::render(&x)))")
+            } else {
+                "None".into()
+            }
+        };
+
+        entries.push_str(&format!(
+            "    Solution {{\n        id: PuzzleId::new(Year::__new_unchecked({}), advent_of_code::day!({})),\n        part_one: {},\n        part_two: {},\n    }},\n",
+            solution.year,
+            solution.day,
+            part("part_one", solution.part_one),
+            part("part_two", solution.part_two),
+        ));
+    }
+
+    let registry = format!(
+        "// @generated by build.rs, do not edit.\n\
+        use advent_of_code::template::registry::Solution;\n\
+        #[cfg(not(test))]\n\
+        use advent_of_code::template::{{PuzzleId, Year}};\n\n\
+        {modules}\
+        // the solution modules contain their own tests, which already run as part of their binaries.\n\
+        #[cfg(test)]\n\
+        pub const SOLUTIONS: &[Solution] = &[];\n\n\
+        #[cfg(not(test))]\n\
+        pub const SOLUTIONS: &[Solution] = &[\n{entries}];\n"
+    );
+
+    fs::write(out_path, registry).unwrap();
+}
+
+struct SolutionFile {
+    path: String,
+    year: u16,
+    day: u8,
+    part_one: bool,
+    part_two: bool,
+}
+
+/// Finds all solution binaries named `<year>-<day>.rs` and which parts their `solution!` invocation runs.
+fn find_solutions(dir: &Path) -> Vec<SolutionFile> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut solutions: Vec<SolutionFile> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let (year, day) = path.file_stem()?.to_str()?.split_once('-')?;
+            let (year, day) = (year.parse().ok()?, day.parse().ok()?);
+
+            let content = fs::read_to_string(&path).ok()?;
+            let start = content.find("solution!(")? + "solution!(".len();
+            let end = content[start..].find(')')? + start;
+            let args: Vec<&str> = content[start..end].split(',').map(str::trim).collect();
+
+            Some(SolutionFile {
+                path: path.to_str()?.to_string(),
+                year,
+                day,
+                part_one: args.get(1).is_none_or(|x| *x == "1"),
+                part_two: args.get(1).is_none_or(|x| *x == "2"),
+            })
+        })
+        .collect();
+
+    solutions.sort_unstable_by_key(|s| (s.year, s.day));
+    solutions
+}
@@ -0,0 +1,6 @@
+//! Links all solutions into one binary, see `template::registry`. Invoked by `cargo all` and `cargo time`.
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+fn main() {
+    advent_of_code::template::registry::run(SOLUTIONS);
+}
@@ -1,40 +1,65 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, dev, download, examples, read, scaffold, solve, time,
+};
 use args::{parse, AppArguments};
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{legacy_data, PuzzleId, Year};
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{
+        commands::{scaffold, solve},
+        DayFilter, DaySelection, PuzzleId, RunOptions, Year,
+    };
     use std::process;
+    use std::time::Duration;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            id: PuzzleId,
         },
         Read {
-            day: Day,
+            id: PuzzleId,
+            refresh: bool,
+        },
+        Examples {
+            id: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            id: PuzzleId,
             download: bool,
             overwrite: bool,
+            template: String,
         },
         Solve {
-            day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            year: Year,
+            selection: DaySelection,
+            filter: DayFilter,
+            options: solve::Options,
         },
         All {
-            release: bool,
+            year: Year,
+            selection: DaySelection,
+            filter: DayFilter,
+            options: RunOptions,
+            verify: bool,
         },
         Time {
+            year: Year,
+            selection: Option<DaySelection>,
+            filter: DayFilter,
+            options: RunOptions,
             all: bool,
-            day: Option<Day>,
             store: bool,
+            threshold: Option<f64>,
+        },
+        History {
+            id: PuzzleId,
+        },
+        Dev {
+            id: PuzzleId,
+            release: bool,
         },
         #[cfg(feature = "today")]
         Today,
@@ -43,37 +68,108 @@ mod args {
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+        let subcommand = args.subcommand()?;
+
+        // `--year` applies to every command, it defaults to `AOC_YEAR` as configured in `.cargo/config.toml`.
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+        let year = year
+            .or_else(Year::from_env)
+            .ok_or("no year specified, pass `--year` or set `AOC_YEAR`.");
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let filter = parse_filter(&mut args)?;
+                let verify = args.contains("--verify");
+                let options = RunOptions {
+                    part: parse_part(&mut args)?,
+                    is_release: args.contains("--release"),
+                    jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                    timeout: parse_timeout(&mut args)?,
+                };
+
+                AppArguments::All {
+                    year: year?,
+                    selection: args.opt_free_from_str()?.unwrap_or_else(DaySelection::all),
+                    filter,
+                    options,
+                    verify,
+                }
+            }
+            Some("time") if args.contains("--history") => AppArguments::History {
+                id: PuzzleId::new(year?, args.free_from_str()?),
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let filter = parse_filter(&mut args)?;
+                // timings are always taken from release builds, one day at a time.
+                let options = RunOptions {
+                    part: parse_part(&mut args)?,
+                    is_release: true,
+                    jobs: 1,
+                    timeout: parse_timeout(&mut args)?,
+                };
 
                 AppArguments::Time {
+                    year: year?,
+                    selection: args.opt_free_from_str()?,
+                    filter,
+                    options,
                     all,
-                    day: args.opt_free_from_str()?,
                     store,
+                    threshold,
+                }
+            }
+            Some("dev") => {
+                let release = args.contains("--release");
+                AppArguments::Dev {
+                    id: PuzzleId::new(year?, args.free_from_str()?),
+                    release,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                id: PuzzleId::new(year?, args.free_from_str()?),
             },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+            Some("read") => {
+                let refresh = args.contains("--refresh");
+                AppArguments::Read {
+                    id: PuzzleId::new(year?, args.free_from_str()?),
+                    refresh,
+                }
+            }
+            Some("examples") => AppArguments::Examples {
+                id: PuzzleId::new(year?, args.free_from_str()?),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                id: PuzzleId::new(year?, args.free_from_str()?),
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let filter = parse_filter(&mut args)?;
+                let options = solve::Options {
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
+                    record: args.contains("--record"),
+                    input: args.opt_value_from_str("--input")?,
+                    all_inputs: args.contains("--all-inputs"),
+                    part: parse_part(&mut args)?,
+                    example: args.contains("--example"),
+                    example_part: args.opt_value_from_str("--example-part")?,
+                };
+
+                AppArguments::Solve {
+                    year: year?,
+                    selection: args.free_from_str()?,
+                    filter,
+                    options,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -93,42 +189,94 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Filters that narrow down the selected days of `solve`, `all` and `time`.
+    fn parse_filter(args: &mut pico_args::Arguments) -> Result<DayFilter, pico_args::Error> {
+        Ok(DayFilter {
+            only_unsolved: args.contains("--only-unsolved"),
+            only_slow: args.opt_value_from_str("--only-slow")?,
+        })
+    }
+
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Duration, Box<dyn std::error::Error>> {
+        let timeout: Option<f64> = args.opt_value_from_str("--timeout")?;
+        match timeout {
+            Some(secs) => Duration::try_from_secs_f64(secs).map_err(|_| {
+                format!("invalid timeout `{secs}`, expecting a number of seconds.").into()
+            }),
+            None => Ok(RunOptions::default().timeout),
+        }
+    }
+
+    fn parse_part(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<u8>, Box<dyn std::error::Error>> {
+        let part: Option<u8> = args.opt_value_from_str("--part")?;
+        match part {
+            Some(1 | 2) | None => Ok(part),
+            Some(part) => Err(format!("invalid part `{part}`, expecting 1 or 2.").into()),
+        }
+    }
 }
 
 fn main() {
+    legacy_data::migrate(Year::from_env());
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                selection,
+                filter,
+                options,
+                verify,
+            } => all::handle(year, &selection, &filter, &options, verify),
+            AppArguments::Time {
+                year,
+                selection,
+                filter,
+                options,
+                all,
+                store,
+                threshold,
+            } => time::handle(year, selection, &filter, &options, all, store, threshold),
+            AppArguments::History { id } => time::handle_history(id),
+            AppArguments::Dev { id, release } => dev::handle(id, release),
+            AppArguments::Download { id } => download::handle(id),
+            AppArguments::Read { id, refresh } => read::handle(id, refresh),
+            AppArguments::Examples { id } => examples::handle(id),
             AppArguments::Scaffold {
-                day,
+                id,
                 download,
                 overwrite,
+                template,
+            } => scaffold::handle(id, overwrite, &template, download),
+            AppArguments::Solve {
+                year,
+                selection,
+                filter,
+                options,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
+                let ids: Vec<PuzzleId> = filter
+                    .apply(year, &selection)
+                    .sorted()
+                    .into_iter()
+                    .map(|day| PuzzleId::new(year, day))
+                    .collect();
+                solve::handle(&ids, &options);
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match PuzzleId::today() {
+                    Some(id) => {
+                        scaffold::handle(id, false, scaffold::DEFAULT_TEMPLATE, true);
+                        read::handle(id, false)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,108 @@
+/// Answers returned by solution parts.
+///
+/// Most answers are numbers, but some puzzles draw their answer, e.g. as letters made of `#`. Multi-line answers are
+/// printed as a framed block, and letters drawn in the common 6 pixel high font are submitted as text.
+use crate::grid::Grid;
+use crate::template::ocr;
+
+/// A value that can be returned by a solution part.
+pub trait Answer {
+    /// The answer as printed in the terminal, may span multiple lines.
+    fn render(&self) -> String;
+
+    /// The answer as submitted and compared with known answers.
+    /// Multi-line answers are decoded to the letters they draw if possible.
+    fn submission(&self) -> String {
+        let rendered = self.render();
+        if is_multi_line(&rendered) {
+            ocr::decode(&rendered).unwrap_or(rendered)
+        } else {
+            rendered
+        }
+    }
+}
+
+macro_rules! impl_answer_for_display {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn render(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_for_display!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String, &str
+);
+
+/// A grid of letters, e.g. drawn with `#` and `.`.
+impl Answer for Grid<u8> {
+    fn render(&self) -> String {
+        self.to_string().trim_end_matches('\n').to_string()
+    }
+}
+
+pub fn is_multi_line(answer: &str) -> bool {
+    answer.trim_end().contains('\n')
+}
+
+/// Draw a border around a multi-line answer.
+pub fn frame(answer: &str) -> String {
+    let lines: Vec<&str> = answer.trim_end().lines().collect();
+    let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let border = "─".repeat(width);
+
+    let mut framed = format!("┌{border}┐\n");
+    for line in lines {
+        let padding = " ".repeat(width - line.chars().count());
+        framed.push_str(&format!("│{line}{padding}│\n"));
+    }
+    framed.push_str(&format!("└{border}┘"));
+    framed
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{frame, Answer};
+    use crate::grid::Grid;
+
+    const LETTERS: &str = "\
+#..#.####.
+#..#.#....
+####.###..
+#..#.#....
+#..#.#....
+#..#.####.";
+
+    #[test]
+    fn renders_numbers_and_strings() {
+        assert_eq!(42_u32.render(), "42");
+        assert_eq!((-7_isize).submission(), "-7");
+        assert_eq!("a,b,c".submission(), "a,b,c");
+    }
+
+    #[test]
+    fn submits_decoded_letters() {
+        let grid = Grid::from(format!("{LETTERS}\n").as_str());
+
+        assert_eq!(grid.render(), LETTERS);
+        assert_eq!(grid.submission(), "HE");
+        assert_eq!(LETTERS.to_string().submission(), "HE");
+    }
+
+    #[test]
+    fn keeps_undecodable_drawings() {
+        let drawing = "#.#\n.#.\n";
+        assert_eq!(drawing.submission(), drawing);
+    }
+
+    #[test]
+    fn frames_multi_line_answers() {
+        assert_eq!(frame("ab\nc\n"), "┌──┐\n│ab│\n│c │\n└──┘");
+    }
+}
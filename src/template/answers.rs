@@ -0,0 +1,334 @@
+/// Known-correct answers, used to verify solutions after refactoring them.
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::results::PartResult;
+use crate::template::{Day, Year};
+
+static ANSWERS_FILE_NAME: &str = "answers.json";
+
+/// Every year keeps its own answers file, e.g. `data/2024/answers.json`.
+fn get_file_path(year: Year) -> String {
+    format!("{}/{ANSWERS_FILE_NAME}", year.data_dir())
+}
+
+/// Represents the known answer to a single part of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnownAnswer {
+    pub day: Day,
+    pub part: u8,
+    /// Name of the input the answer belongs to, `None` for the default input.
+    pub input: Option<String>,
+    pub answer: String,
+}
+
+/// Represents the known answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<KnownAnswer>,
+}
+
+/// Result of comparing an answer with the known answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verification {
+    /// No answer has been recorded for this part yet.
+    Unknown,
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Unknown => Ok(()),
+            Verification::Correct => write!(f, " ✔"),
+            Verification::Incorrect { expected } => write!(f, " ✘ (expected {expected})"),
+        }
+    }
+}
+
+/// A part whose answer does not match the known answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    pub day: Day,
+    pub part: u8,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} Part {}: expected {}, got {}",
+            self.day,
+            self.part,
+            self.expected,
+            self.actual.as_deref().unwrap_or("✖")
+        )
+    }
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns no answers.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_file_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: Day, part: u8, input: Option<&str>) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input.as_deref() == input)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Record `answer` as the known answer, replacing a previous one.
+    pub fn set(&mut self, day: Day, part: u8, input: Option<&str>, answer: &str) {
+        match self
+            .data
+            .iter_mut()
+            .find(|a| a.day == day && a.part == part && a.input.as_deref() == input)
+        {
+            Some(known) => answer.clone_into(&mut known.answer),
+            None => {
+                self.data.push(KnownAnswer {
+                    day,
+                    part,
+                    input: input.map(Into::into),
+                    answer: answer.into(),
+                });
+                self.data.sort_unstable_by(|a, b| {
+                    (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input))
+                });
+            }
+        }
+    }
+
+    pub fn check(&self, day: Day, part: u8, input: Option<&str>, answer: &str) -> Verification {
+        match self.get(day, part, input) {
+            None => Verification::Unknown,
+            Some(expected) if expected == answer => Verification::Correct,
+            Some(expected) => Verification::Incorrect {
+                expected: expected.into(),
+            },
+        }
+    }
+
+    /// Collect all results of the default input that do not match a known answer.
+    /// Parts without a known answer are ignored.
+    pub fn verify(&self, results: &[PartResult]) -> Vec<Mismatch> {
+        results
+            .iter()
+            .filter_map(|r| {
+                let expected = self.get(r.day, r.part, None)?;
+                (r.answer.as_deref() != Some(expected)).then(|| Mismatch {
+                    day: r.day,
+                    part: r.part,
+                    expected: expected.into(),
+                    actual: r.answer.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(KnownAnswer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&KnownAnswer> for JsonValue {
+    fn from(value: &KnownAnswer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        if let Some(input) = &value.input {
+            map.insert("input".into(), JsonValue::String(input.clone()));
+        }
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for KnownAnswer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .filter(|x| *x == 1 || *x == 2)
+            .ok_or("Expected answer.part to be 1 or 2.")?;
+
+        let input = match json.get("input") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected answer.input to be null or string.")?,
+            ),
+        };
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected answer.answer to be a string.")?;
+
+        Ok(KnownAnswer {
+            day,
+            part,
+            input: input.cloned(),
+            answer: answer.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verification};
+    use crate::{day, template::results::PartResult};
+    use tinyjson::JsonValue;
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.set(day!(13), 2, None, "875318608908");
+        answers.set(day!(1), 1, None, "11");
+        answers.set(day!(1), 1, Some("alice"), "12");
+        answers
+    }
+
+    #[test]
+    fn sets_and_replaces_answers() {
+        let mut answers = get_mock_answers();
+        assert_eq!(answers.data[0].day, day!(1));
+        answers.set(day!(1), 1, None, "13");
+        assert_eq!(answers.data.len(), 3);
+        assert_eq!(answers.get(day!(1), 1, None), Some("13"));
+        assert_eq!(answers.get(day!(1), 1, Some("alice")), Some("12"));
+        assert_eq!(answers.get(day!(1), 1, Some("bob")), None);
+        assert_eq!(answers.get(day!(1), 2, None), None);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.check(day!(1), 1, None, "11"), Verification::Correct);
+        assert_eq!(
+            answers.check(day!(1), 1, None, "12"),
+            Verification::Incorrect {
+                expected: "11".into()
+            }
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, Some("alice"), "12"),
+            Verification::Correct
+        );
+        assert_eq!(answers.check(day!(2), 1, None, "11"), Verification::Unknown);
+    }
+
+    #[test]
+    fn verifies_results() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers
+                .verify(&[
+                    PartResult::answered(day!(13), 1, Some("1"), 0.0),
+                    PartResult::answered(day!(13), 2, Some("875318608908"), 0.0)
+                ])
+                .len(),
+            0
+        );
+
+        let mismatches = answers.verify(&[PartResult::answered(day!(13), 2, None, 0.0)]);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(
+            mismatches[0].to_string(),
+            "Day 13 Part 2: expected 875318608908, got ✖"
+        );
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let json = JsonValue::from(get_mock_answers()).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.data, get_mock_answers().data);
+    }
+
+    #[test]
+    fn reads_default_input_without_input_key() {
+        let json = r#"{ "data": [{ "day": "01", "part": 1, "answer": "11" }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), 1, None), Some("11"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part": 1 }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+}
@@ -1,125 +1,373 @@
-/// Wrapper module around the "aoc-cli" command-line.
-use std::{
-    fmt::Display,
-    process::{Command, Output, Stdio},
-};
+/// Client for adventofcode.com. Downloads inputs and puzzle descriptions and submits answers.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable or from `~/.adventofcode.session`
+/// (the same file aoc-cli uses). The base URL can be overridden with `AOC_BASE_URL`.
+use std::{env, fmt::Display, fs, io, path::PathBuf};
 
-use crate::template::Day;
+use crate::template::{puzzle, PuzzleId};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "github.com/m4r1vs/aoc2024 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
 
 #[derive(Debug)]
-pub enum AocCommandError {
-    CommandNotFound,
-    CommandNotCallable,
-    BadExitStatus(Output),
+pub enum AocError {
+    /// No session cookie was found in the environment or config file.
+    MissingSession,
+    /// The session cookie was rejected.
+    Unauthorized,
+    /// The puzzle does not exist or has not been unlocked yet.
+    NotFound,
+    /// The server asked us to slow down.
+    RateLimited,
+    /// The server responded with an unexpected status code.
+    HttpStatus(u16),
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    IO(io::Error),
 }
 
-impl Display for AocCommandError {
+impl Display for AocError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-            AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-            AocCommandError::BadExitStatus(_) => {
-                write!(f, "aoc-cli exited with a non-zero status.")
+            AocError::MissingSession => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or write it to `~/.adventofcode.session`."
+            ),
+            AocError::Unauthorized => {
+                write!(f, "the session cookie was rejected, it may have expired.")
+            }
+            AocError::NotFound => write!(f, "the puzzle is not available (yet)."),
+            AocError::RateLimited => write!(f, "too many requests, please try again later."),
+            AocError::HttpStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
             }
+            AocError::Transport(e) => write!(f, "request failed: {e}"),
+            AocError::IO(e) => write!(f, "{e}"),
         }
     }
 }
 
-pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
-        .arg("-V")
-        .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
-    Ok(())
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::IO(e)
+    }
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+impl From<ureq::Error> for AocError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400 | 401 | 403, _) => AocError::Unauthorized,
+            ureq::Error::Status(404, _) => AocError::NotFound,
+            ureq::Error::Status(429, _) => AocError::RateLimited,
+            ureq::Error::Status(status, _) => AocError::HttpStatus(status),
+            ureq::Error::Transport(e) => AocError::Transport(e.to_string()),
+        }
+    }
+}
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
+/// A configured connection to adventofcode.com (or a server mimicking it).
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
 
-    call_aoc_cli(&args)
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client from the environment and config files.
+    pub fn from_env() -> Result<Self, AocError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = get_session().ok_or(AocError::MissingSession)?;
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn day_url(&self, id: PuzzleId) -> String {
+        format!("{}/{}/day/{}", self.base_url, id.year, id.day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn fetch_input(&self, id: PuzzleId) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.day_url(id)))
+    }
+
+    /// Fetches the puzzle page and returns the HTML of its `<article>` elements.
+    pub fn fetch_puzzle(&self, id: PuzzleId) -> Result<String, AocError> {
+        let page = self.get(&self.day_url(id))?;
+        Ok(extract_articles(&page))
+    }
+
+    /// Submits an answer and returns the text of the response.
+    pub fn post_answer(&self, id: PuzzleId, part: u8, answer: &str) -> Result<String, AocError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(id)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(strip_tags(&extract_articles(&response.into_string()?)))
+    }
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+/// Verifies that a session cookie is configured.
+pub fn check() -> Result<(), AocError> {
+    Client::from_env().map(|_| ())
+}
 
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
+/// Prints the puzzle description from the cached copy. The puzzle is only fetched if there is no cached copy yet,
+/// or if `refresh` is set, e.g. to read part two. If refreshing fails, the cached copy is printed as is.
+pub fn read(id: PuzzleId, refresh: bool) -> Result<(), AocError> {
+    let cached = fs::read_to_string(get_puzzle_path(id)).ok();
+
+    let puzzle = match cached {
+        Some(puzzle) if !refresh => puzzle,
+        cached => match Client::from_env().and_then(|client| client.fetch_puzzle(id)) {
+            Ok(puzzle) => {
+                store_puzzle(id, &puzzle)?;
+                puzzle
+            }
+            Err(e) => match cached {
+                Some(puzzle) => {
+                    eprintln!("Could not refresh puzzle ({e}), showing cached copy.");
+                    puzzle
+                }
+                None => return Err(e),
+            },
+        },
+    };
+
+    println!("{}", puzzle::to_terminal(&puzzle));
+    Ok(())
+}
+
+pub fn download(id: PuzzleId) -> Result<(), AocError> {
+    let client = Client::from_env()?;
+    let input_path = get_input_path(id);
+
+    fs::create_dir_all(format!("{}/inputs", id.year.data_dir()))?;
+    fs::write(&input_path, client.fetch_input(id)?)?;
+    store_puzzle(id, &client.fetch_puzzle(id)?)?;
 
-    let output = call_aoc_cli(&args)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        get_markdown_path(id)
+    );
+    Ok(())
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
+/// Caches the raw puzzle HTML and writes a Markdown version next to it.
+fn store_puzzle(id: PuzzleId, html: &str) -> Result<(), AocError> {
+    fs::create_dir_all(format!("{}/puzzles", id.year.data_dir()))?;
+    fs::write(get_puzzle_path(id), html)?;
+    fs::write(get_markdown_path(id), puzzle::to_markdown(html))?;
+    Ok(())
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+/// Submits an answer and returns the text of the response.
+pub fn submit(id: PuzzleId, part: u8, result: &str) -> Result<String, AocError> {
+    let response = Client::from_env()?.post_answer(id, part, result)?;
+    println!("{response}");
+    Ok(response)
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn get_input_path(id: PuzzleId) -> String {
+    id.data_file("inputs")
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+fn get_puzzle_path(id: PuzzleId) -> String {
+    format!("{}/puzzles/{}.html", id.year.data_dir(), id.day)
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
+pub fn get_markdown_path(id: PuzzleId) -> String {
+    format!("{}/puzzles/{}.md", id.year.data_dir(), id.day)
+}
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION").or_else(|_| env::var("ADVENT_OF_CODE_SESSION")) {
+        return Some(session.trim().to_string());
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .map_or_else(|_| PathBuf::from(&home).join(".config"), PathBuf::from);
 
-    cmd_args
+    [
+        PathBuf::from(&home).join(".adventofcode.session"),
+        config_dir.join("adventofcode.session"),
+    ]
+    .iter()
+    .find_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .filter(|session| !session.is_empty())
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
-    // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
-        .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
+/// Returns the concatenated `<article>` elements of a page, or the whole page if there are none.
+fn extract_articles(html: &str) -> String {
+    let articles: Vec<&str> = html
+        .match_indices("<article")
+        .filter_map(|(start, _)| {
+            let end = html[start..].find("</article>")? + start + "</article>".len();
+            Some(&html[start..end])
+        })
+        .collect();
 
-    if output.status.success() {
-        Ok(output)
+    if articles.is_empty() {
+        html.into()
     } else {
-        Err(AocCommandError::BadExitStatus(output))
+        articles.join("\n")
+    }
+}
+
+/// Removes all tags from an HTML fragment and decodes the common entities.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AocError, Client};
+    use crate::{
+        day,
+        template::{Day, PuzzleId, Year},
+    };
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    fn id(day: Day) -> PuzzleId {
+        PuzzleId::new(Year::new(2024).unwrap(), day)
+    }
+
+    /// Serves a single request with `status` and `body`, returning the raw request.
+    fn mock_server(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (url, server) = mock_server("200 OK", "1 2\n3 4\n");
+        let input = Client::new(&url, "abc").fetch_input(id(day!(1))).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1 2\n3 4\n");
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzle_articles() {
+        let page = "<html><main><article class=\"day-desc\"><h2>--- Day 5 ---</h2></article><p>other</p></main></html>";
+        let (url, server) = mock_server("200 OK", page);
+        let puzzle = Client::new(&url, "abc").fetch_puzzle(id(day!(5))).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(
+            puzzle,
+            "<article class=\"day-desc\"><h2>--- Day 5 ---</h2></article>"
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let page = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>";
+        let (url, server) = mock_server("200 OK", page);
+        let response = Client::new(&url, "abc")
+            .post_answer(id(day!(13)), 2, "480")
+            .unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(
+            response,
+            "That's the right answer! You are one gold star closer."
+        );
+        assert!(request.starts_with("POST /2024/day/13/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=480"));
+    }
+
+    #[test]
+    fn maps_status_codes() {
+        let (url, server) = mock_server("400 Bad Request", "Please log in");
+        let result = Client::new(&url, "expired").fetch_input(id(day!(1)));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocError::Unauthorized)));
+
+        let (url, server) = mock_server("404 Not Found", "");
+        let result = Client::new(&url, "abc").fetch_input(id(day!(25)));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocError::NotFound)));
     }
 }
@@ -1,5 +1,46 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::answers::Answers;
+use crate::template::{
+    run_multi::run_multi, summary, DayFilter, DaySelection, RunOptions, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Exits with a non-zero code if a part failed, returned no answer or, with `verify`, changed its answer.
+pub fn handle(
+    year: Year,
+    selection: &DaySelection,
+    filter: &DayFilter,
+    options: &RunOptions,
+    verify: bool,
+) {
+    let days_to_run = filter.apply(year, selection);
+
+    if days_to_run.days().is_empty() {
+        println!("No days selected.");
+        return;
+    }
+
+    let run = run_multi(year, days_to_run.days(), false, options);
+
+    let mismatches = if verify {
+        Answers::read_from_file(year).verify(&run.results)
+    } else {
+        vec![]
+    };
+
+    if verify {
+        if mismatches.is_empty() {
+            println!("\n{ANSI_BOLD}All known answers verified.{ANSI_RESET}");
+        } else {
+            eprintln!("\n{ANSI_BOLD}Answers changed:{ANSI_RESET}");
+            for mismatch in &mismatches {
+                eprintln!("{mismatch}");
+            }
+        }
+    }
+
+    let summaries = summary::summarize(&run.results, &mismatches);
+    summary::print(&summaries);
+
+    process::exit(summary::exit_code(&summaries));
 }
@@ -0,0 +1,185 @@
+use std::{
+    env, fs,
+    process::{self, Command},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::results::{PartResult, RESULTS_FILE_ENV};
+use crate::template::{PuzzleId, ANSI_BOLD, ANSI_RESET};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Reruns a day whenever its solution, the shared grid module or its examples change.
+/// Every run tests the solution against the examples first and only solves the real input if the tests pass.
+pub fn handle(id: PuzzleId, release: bool) {
+    let files = watched_files(id);
+    println!(
+        "Watching {} for changes, press Ctrl-C to stop.",
+        files.join(", ")
+    );
+
+    let mut modified = modified_times(&files);
+    let mut previous: Option<Vec<PartResult>> = None;
+
+    loop {
+        if let Some(results) = run(id, release) {
+            if let Some(previous) = &previous {
+                println!("\n{ANSI_BOLD}Compared to last run:{ANSI_RESET}");
+                for line in diff_answers(previous, &results) {
+                    println!("{line}");
+                }
+            }
+            previous = Some(results);
+        }
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = modified_times(&files);
+
+            if current != modified {
+                let changed: Vec<&str> = files
+                    .iter()
+                    .zip(current.iter().zip(&modified))
+                    .filter(|(_, (a, b))| a != b)
+                    .map(|(file, _)| file.as_str())
+                    .collect();
+
+                println!("\n{ANSI_BOLD}Changed:{ANSI_RESET} {}", changed.join(", "));
+                modified = current;
+                break;
+            }
+        }
+    }
+}
+
+fn watched_files(id: PuzzleId) -> Vec<String> {
+    vec![
+        id.bin_path(),
+        "./src/grid.rs".into(),
+        id.data_file("examples"),
+        format!("{}/examples/{}-2.txt", id.year.data_dir(), id.day),
+    ]
+}
+
+/// Modification times of `files`, `None` for files that do not exist.
+fn modified_times(files: &[String]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Test the solution against the examples, then solve the real input. Returns `None` if either step failed.
+fn run(id: PuzzleId, release: bool) -> Option<Vec<PartResult>> {
+    let bin = id.to_string();
+
+    println!("\n{ANSI_BOLD}Testing {id} against examples{ANSI_RESET}");
+    let tests = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &bin])
+        .status();
+
+    if !tests.is_ok_and(|status| status.success()) {
+        eprintln!("\nExample tests failed, not solving the real input.");
+        return None;
+    }
+
+    println!("\n{ANSI_BOLD}Solving {id}{ANSI_RESET}");
+
+    // answers are reported through a side channel file, see `template::results`.
+    let results_path = env::temp_dir().join(format!("aoc-dev-{}-{id}.jsonl", process::id()));
+    let _ = fs::remove_file(&results_path);
+
+    let mut args = vec!["run", "--quiet", "--bin", &bin];
+    if release {
+        args.push("--release");
+    }
+
+    let status = Command::new("cargo")
+        .args(&args)
+        .env(RESULTS_FILE_ENV, &results_path)
+        .status();
+
+    let results = PartResult::read_all(&results_path);
+    let _ = fs::remove_file(&results_path);
+
+    match (status, results) {
+        (Ok(status), Ok(results)) if status.success() => Some(results),
+        _ => None,
+    }
+}
+
+/// Describe how the answer of every part changed since the previous run.
+fn diff_answers(previous: &[PartResult], current: &[PartResult]) -> Vec<String> {
+    let answer = |results: &[PartResult], part: u8| {
+        results
+            .iter()
+            .find(|r| r.part == part)
+            .map(|r| r.answer.clone())
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter_map(|part| {
+            let (old, new) = (answer(previous, part), answer(current, part));
+            if old.is_none() && new.is_none() {
+                return None;
+            }
+
+            let (old, new) = (old.flatten(), new.flatten());
+            let line = if old == new {
+                format!("Part {part}: unchanged")
+            } else {
+                format!(
+                    "Part {part}: {} → {ANSI_BOLD}{}{ANSI_RESET}",
+                    old.as_deref().unwrap_or("✖"),
+                    new.as_deref().unwrap_or("✖")
+                )
+            };
+            Some(line)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::diff_answers;
+    use crate::{
+        day,
+        template::{results::PartResult, ANSI_BOLD, ANSI_RESET},
+    };
+
+    #[test]
+    fn diffs_answers() {
+        let previous = [
+            PartResult::answered(day!(1), 1, Some("10"), 0.0),
+            PartResult::answered(day!(1), 2, None, 0.0),
+        ];
+        let current = [
+            PartResult::answered(day!(1), 1, Some("10"), 0.0),
+            PartResult::answered(day!(1), 2, Some("20"), 0.0),
+        ];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            [
+                "Part 1: unchanged".to_string(),
+                format!("Part 2: ✖ → {ANSI_BOLD}20{ANSI_RESET}")
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_parts_that_did_not_run() {
+        let previous = [PartResult::answered(day!(1), 1, Some("10"), 0.0)];
+        let current = [PartResult::answered(day!(1), 1, Some("11"), 0.0)];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            [format!("Part 1: 10 → {ANSI_BOLD}11{ANSI_RESET}")]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use std::{fs, io, process};
+
+use crate::template::{aoc_cli, puzzle, PuzzleId, Year};
+
+/// Writes the examples found in the cached puzzle description of `id`.
+/// Returns the paths that were written, which is empty if the description is not cached or contains no example.
+/// Existing example files are only replaced if they are empty or `overwrite` is set.
+pub fn write_examples(id: PuzzleId, overwrite: bool) -> Result<Vec<String>, io::Error> {
+    let Ok(markdown) = fs::read_to_string(aoc_cli::get_markdown_path(id)) else {
+        return Ok(vec![]);
+    };
+
+    let examples = puzzle::extract_examples(&markdown);
+    let files = [
+        (id.data_file("examples"), examples.part_one),
+        (
+            format!("{}/examples/{}-2.txt", id.year.data_dir(), id.day),
+            examples.part_two,
+        ),
+    ];
+
+    fs::create_dir_all(format!("{}/examples", id.year.data_dir()))?;
+    let mut written = vec![];
+
+    for (path, example) in files {
+        let Some(example) = example else {
+            continue;
+        };
+
+        let is_empty = fs::read_to_string(&path).map_or(true, |s| s.trim().is_empty());
+        if overwrite || is_empty {
+            fs::write(&path, example)?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+pub fn handle(id: PuzzleId) {
+    match write_examples(id, true) {
+        Ok(written) if written.is_empty() => {
+            let download = if Year::from_env() == Some(id.year) {
+                format!("cargo download {}", id.day)
+            } else {
+                format!("cargo download {} --year {}", id.day, id.year)
+            };
+            eprintln!(
+                "No example found for {id}. Run `{download}` to cache the puzzle description."
+            );
+            process::exit(1);
+        }
+        Ok(written) => {
+            for path in written {
+                println!("Wrote example file \"{path}\"");
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod all;
+pub mod dev;
 pub mod download;
+pub mod examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
@@ -1,13 +1,41 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{aoc_cli, commands::examples, puzzle, PuzzleId, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Module templates are looked up by name in this directory, e.g. `templates/grid.txt` for `--template grid`.
+const TEMPLATES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/templates");
+
+pub const DEFAULT_TEMPLATE: &str = "plain";
+
+fn read_template(name: &str) -> Result<String, String> {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+    fs::read_to_string(&path).map_err(|_| {
+        format!(
+            "Unknown template \"{name}\". Available templates: {}",
+            available_templates().join(", ")
+        )
+    })
+}
+
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|x| x == "txt"))
+                .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort_unstable();
+    names
+}
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +47,85 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty file, unless it already exists with some content (e.g. a downloaded input).
+/// Returns whether the file was created.
+fn create_empty_file(path: &str) -> Result<bool, std::io::Error> {
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(false);
+    }
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    File::create(path).map(|_| true)
+}
+
+/// Fills empty example files from the cached puzzle description, if there is one.
+fn extract_examples(id: PuzzleId) {
+    match examples::write_examples(id, false) {
+        Ok(written) => {
+            for path in written {
+                println!("Extracted example file \"{path}\"");
+            }
+        }
+        Err(e) => eprintln!("Failed to extract examples: {e}"),
+    }
+}
+
+/// Fills in the placeholders of a module template. Title, types and expected answers of the tests are taken from the
+/// puzzle description if it has been downloaded before.
+fn render_module(template: &str, id: PuzzleId, markdown: &str) -> String {
+    // negative answers do not fit the inferred types, their tests are left to fill in.
+    let answers = puzzle::extract_answers(markdown).map(|x| x.and_then(|x| u64::try_from(x).ok()));
+    let has_part_two_example = puzzle::extract_examples(markdown).part_two.is_some();
+
+    let part_two_example = if has_part_two_example {
+        "advent_of_code::template::read_file_part(\"examples\", DAY, 2)"
+    } else {
+        "advent_of_code::template::read_file(\"examples\", DAY)"
+    };
+
+    let title =
+        puzzle::extract_title(markdown).unwrap_or_else(|| format!("Day {}", id.day.into_inner()));
+
+    template
+        .replace("%DAY_NUMBER%", &id.day.into_inner().to_string())
+        .replace("%YEAR%", &id.year.to_string())
+        .replace("%TITLE%", &title)
+        .replace("%PART_ONE_TYPE%", answer_type(answers[0]))
+        .replace("%PART_TWO_TYPE%", answer_type(answers[1]))
+        .replace("%PART_ONE_ANSWER%", &expected_answer(answers[0]))
+        .replace("%PART_TWO_ANSWER%", &expected_answer(answers[1]))
+        .replace("%PART_TWO_EXAMPLE%", part_two_example)
+}
+
+/// Picks the type of an answer from its magnitude. Real inputs tend to have larger answers than the examples,
+/// so adjust as needed.
+fn answer_type(answer: Option<u64>) -> &'static str {
+    match answer {
+        Some(x) if x > u64::from(u32::MAX) => "usize",
+        _ => "u32",
+    }
+}
+
+fn expected_answer(answer: Option<u64>) -> String {
+    answer.map_or_else(|| "None".into(), |x| format!("Some({x})"))
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Creates the module, input and example files of `id`. With `download`, the puzzle is downloaded afterwards and the
+/// module is filled in from its description. A failed download is reported, but keeps the scaffolded files: their
+/// tests then expect `None` until the example answers are filled in.
+pub fn handle(id: PuzzleId, overwrite: bool, template: &str, download: bool) {
+    let input_path = id.data_file("inputs");
+    let example_path = id.data_file("examples");
+    let module_path = id.bin_path();
+
+    let template = match read_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +135,9 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let markdown = fs::read_to_string(aoc_cli::get_markdown_path(id)).unwrap_or_default();
+
+    match file.write_all(render_module(&template, id, &markdown).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +147,115 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
+    match create_empty_file(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
+    match create_empty_file(&example_path) {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
         }
     }
 
+    if download {
+        download_puzzle(id, &template, &module_path);
+    }
+
+    extract_examples(id);
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if Year::from_env() == Some(id.year) {
+        println!("🎄 Type `cargo solve {}` to run your solution.", id.day);
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            id.day, id.year
+        );
+    }
+}
+
+/// Downloads the input and puzzle description, then renders the freshly created module again to pick up the title,
+/// types and expected answers from the description.
+fn download_puzzle(id: PuzzleId, template: &str, module_path: &str) {
+    if let Err(e) = aoc_cli::download(id) {
+        eprintln!("Failed to download, keeping the scaffold without example answers: {e}");
+        return;
+    }
+
+    let Ok(markdown) = fs::read_to_string(aoc_cli::get_markdown_path(id)) else {
+        return;
+    };
+
+    match fs::write(module_path, render_module(template, id, &markdown)) {
+        Ok(()) => println!("Filled in module file \"{module_path}\" from the puzzle description"),
+        Err(e) => eprintln!("Failed to fill in module file: {e}"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_type, available_templates, read_template, render_module};
+    use crate::{
+        day,
+        template::{PuzzleId, Year},
+    };
+
+    fn id() -> PuzzleId {
+        PuzzleId::new(Year::new(2016).unwrap(), day!(1))
+    }
+
+    #[test]
+    fn renders_expected_answers() {
+        let markdown = "## --- Day 1: Test ---\n\nFor example:\n\n```\n1\n```\n\nThis gives *`11`*.\n\n## --- Part Two ---\n\nHere is a larger example:\n\n```\n2\n```\n\nThis gives *5000000000*.\n";
+        let module = render_module(&read_template("plain").unwrap(), id(), markdown);
+
+        assert!(module.starts_with("//! Day 1: Test\n//! https://adventofcode.com/2016/day/1\n"));
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u32>"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<usize>"));
+        assert!(module.contains("assert_eq!(result, Some(11));"));
+        assert!(module.contains("assert_eq!(result, Some(5000000000));"));
+        assert!(module.contains("read_file_part(\"examples\", DAY, 2)"));
+    }
+
+    #[test]
+    fn infers_answer_types_from_magnitude() {
+        assert_eq!(answer_type(None), "u32");
+        assert_eq!(answer_type(Some(0)), "u32");
+        assert_eq!(answer_type(Some(u32::MAX.into())), "u32");
+        assert_eq!(answer_type(Some(u64::from(u32::MAX) + 1)), "usize");
+        assert_eq!(answer_type(Some(u64::MAX)), "usize");
+    }
+
+    #[test]
+    fn renders_all_placeholders() {
+        let templates = available_templates();
+        assert_eq!(templates, ["grid", "parser", "plain", "single-part"]);
+
+        for name in templates {
+            let module = render_module(&read_template(&name).unwrap(), id(), "");
+            assert!(!module.contains('%'), "placeholder left in {name}");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_templates() {
+        let err = read_template("nope").unwrap_err();
+        assert!(err.contains("Available templates: grid, parser, plain, single-part"));
+    }
 }
@@ -1,23 +1,150 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    path::Path,
+    process::{self, Command, Stdio},
+};
 
-use crate::template::Day;
+use crate::template::{PuzzleId, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Flags of `cargo solve`, shared by every selected day.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub release: bool,
+    pub dhat: bool,
+    pub submit: Option<u8>,
+    pub record: bool,
+    pub input: Option<String>,
+    pub all_inputs: bool,
+    pub part: Option<u8>,
+    /// Run on the example instead of the input.
+    pub example: bool,
+    /// Run on the example of a part, e.g. `data/2024/examples/05-2.txt`.
+    pub example_part: Option<u8>,
+}
+
+pub fn handle(ids: &[PuzzleId], options: &Options) {
+    if ids.is_empty() {
+        println!("No days selected.");
+        return;
+    }
+
+    if ids.len() > 1 && options.submit.is_some() {
+        eprintln!("`--submit` can only be used with a single day.");
+        process::exit(1);
+    }
+
+    for (i, id) in ids.iter().enumerate() {
+        if ids.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", id.day);
+            println!("------");
+        }
+        solve_day(*id, options);
+    }
+}
+
+fn solve_day(id: PuzzleId, options: &Options) {
+    if !options.all_inputs {
+        run_solution(id, options, options.submit, options.input.as_deref());
+        return;
+    }
+
+    if options.submit.is_some() {
+        eprintln!("`--submit` cannot be combined with `--all-inputs`.");
+        process::exit(1);
+    }
+
+    if options.example || options.example_part.is_some() {
+        eprintln!("`--example` cannot be combined with `--all-inputs`.");
+        process::exit(1);
+    }
+
+    let has_default_input = Path::new(&id.data_file("inputs")).exists();
+    let names = list_inputs(id);
+
+    if names.is_empty() && !has_default_input {
+        eprintln!("No inputs found for {id}.");
+        process::exit(1);
+    }
+
+    let inputs = has_default_input
+        .then_some(None)
+        .into_iter()
+        .chain(names.iter().map(|name| Some(name.as_str())));
 
-    if dhat {
+    for (i, input) in inputs.enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "{ANSI_BOLD}Input {}{ANSI_RESET}",
+            input.unwrap_or("(default)")
+        );
+        run_solution(id, options, None, input);
+    }
+}
+
+/// Names of the additional inputs of a day, e.g. `alice` for `data/2024/inputs/05/alice.txt`.
+fn list_inputs(id: PuzzleId) -> Vec<String> {
+    let dir = Path::new(&id.year.data_dir())
+        .join("inputs")
+        .join(id.day.to_string());
+
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|x| x == "txt"))
+                .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort_unstable();
+    names
+}
+
+fn run_solution(id: PuzzleId, options: &Options, submit_part: Option<u8>, input: Option<&str>) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), id.to_string()];
+
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
+    if options.record {
+        cmd_args.push("--record".to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.to_string());
+    }
+
+    if options.example {
+        cmd_args.push("--example".to_string());
+    }
+
+    if let Some(part) = options.example_part {
+        cmd_args.push("--example-part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(part) = options.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
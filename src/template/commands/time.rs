@@ -1,34 +1,83 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::history::{self, HistoryEntry};
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
-
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
-
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+use crate::template::{
+    all_days, readme_benchmarks, summary, Day, DayFilter, DaySelection, PuzzleId, RunOptions, Year,
+    ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle(
+    year: Year,
+    selection: Option<DaySelection>,
+    filter: &DayFilter,
+    options: &RunOptions,
+    run_all: bool,
+    store: bool,
+    threshold: Option<f64>,
+) {
+    let stored_timings = Timings::read_from_file(year);
+
+    let selection = selection.unwrap_or_else(|| {
+        if run_all {
+            DaySelection::all()
+        } else {
+            // when the `--all` flag is not set, filter out days that are fully benched.
+            all_days()
+                .filter(|day| !stored_timings.is_day_complete(*day))
+                .collect::<HashSet<Day>>()
+                .into()
+        }
+    });
+
+    let days_to_run = filter.apply(year, &selection);
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    if days_to_run.days().is_empty() {
+        println!("No days selected.");
+        return;
+    }
+
+    let run = run_multi(year, days_to_run.days(), true, options);
+    summary::print_failures(&run.results);
+    let timings = run.timings.unwrap();
+
+    let deltas = timings.compare(&stored_timings);
+
+    if !deltas.is_empty() {
+        println!("\n{ANSI_BOLD}Compared to stored timings:{ANSI_RESET}");
+        for delta in &deltas {
+            println!("{delta}");
+        }
+    }
+
+    if let Some(threshold) = threshold {
+        let regressions = deltas
+            .iter()
+            .filter(|delta| delta.is_regression(threshold))
+            .count();
+
+        if regressions > 0 {
+            eprintln!("\n{regressions} part(s) regressed by more than {threshold}%.");
+            process::exit(1);
+        }
+    }
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        // a single part was benched, keep the stored timing of the other part.
+        let merged_timings = match options.part {
+            Some(part) => stored_timings.merge(&timings.keep_other_part(&stored_timings, part)),
+            None => stored_timings.merge(&timings),
+        };
+        merged_timings.store_file(year).unwrap();
+
+        if let Err(e) = HistoryEntry::new(timings).append(year) {
+            eprintln!("Failed to append to timing history: {e}");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -38,3 +87,13 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+pub fn handle_history(id: PuzzleId) {
+    match history::read_from_file(id.year) {
+        Ok(entries) => println!("{}", history::report(&entries, id.day)),
+        Err(e) => {
+            eprintln!("Failed to read timing history: {e}");
+            process::exit(1);
+        }
+    }
+}
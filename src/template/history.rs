@@ -0,0 +1,310 @@
+/// Append-only log of stored benchmark runs.
+/// Every `cargo time --store` adds one line, which allows to report how the runtime of a day evolved.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::timings::{PartTiming, Timings};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_RESET};
+
+static HISTORY_FILE_NAME: &str = "timings_history.jsonl";
+
+/// Every year keeps its own history file, e.g. `data/2024/timings_history.jsonl`.
+fn get_file_path(year: Year) -> String {
+    format!("{}/{HISTORY_FILE_NAME}", year.data_dir())
+}
+
+/// Represents the timings of one stored run together with where and when it was taken.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Output of `git describe --always --dirty`, if available.
+    pub revision: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub machine: String,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Creates an entry for the current revision, time and machine.
+    pub fn new(timings: Timings) -> Self {
+        Self {
+            revision: git_revision(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            machine: machine_id(),
+            timings,
+        }
+    }
+
+    /// Append the entry to the history file of `year`.
+    pub fn append(&self, year: Year) -> Result<(), Error> {
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| Error::other(e.to_string()))?;
+
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_file_path(year))?;
+        writeln!(file, "{line}")
+    }
+
+    /// Date of the entry formatted as `YYYY-MM-DD` (UTC).
+    pub fn date(&self) -> String {
+        let (year, month, day) = civil_from_days(self.timestamp / 86_400);
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+}
+
+/// Rehydrate all history entries from the history file of `year`. If not present, returns no entries.
+pub fn read_from_file(year: Year) -> Result<Vec<HistoryEntry>, String> {
+    fs::read_to_string(get_file_path(year)).map_or_else(|_| Ok(vec![]), |s| parse_lines(&s))
+}
+
+fn parse_lines(content: &str) -> Result<Vec<HistoryEntry>, String> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("not a valid JSON line."))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+/// Render how both parts of `day` evolved over all entries that contain it.
+pub fn report(entries: &[HistoryEntry], day: Day) -> String {
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Day {day} history{ANSI_RESET}"),
+        format!(
+            "{:<10}  {:<16}  {:<16}  {:<22}  {:<22}",
+            "Date", "Revision", "Machine", "Part 1", "Part 2"
+        ),
+    ];
+
+    let mut previous: [Option<f64>; 2] = [None, None];
+    let mut has_rows = false;
+
+    for entry in entries {
+        let Some(timing) = entry.timings.data.iter().find(|t| t.day == day) else {
+            continue;
+        };
+
+        let parts = [1, 2].map(|part| {
+            let cell = format_cell(timing.part(part), previous[usize::from(part - 1)]);
+            if let Some(nanos) = timing.part_nanos(part) {
+                previous[usize::from(part - 1)] = Some(nanos);
+            }
+            cell
+        });
+
+        has_rows = true;
+        lines.push(format!(
+            "{:<10}  {:<16}  {:<16}  {:<22}  {:<22}",
+            entry.date(),
+            entry.revision.as_deref().unwrap_or("-"),
+            entry.machine,
+            parts[0],
+            parts[1]
+        ));
+    }
+
+    if !has_rows {
+        lines.push("No stored timings.".into());
+    }
+
+    lines.join("\n")
+}
+
+fn format_cell(timing: Option<&PartTiming>, previous_nanos: Option<f64>) -> String {
+    match (timing, previous_nanos) {
+        (Some(timing), Some(previous)) if previous > 0.0 => {
+            format!(
+                "{timing} ({:+.1}%)",
+                (timing.nanos - previous) / previous * 100.0
+            )
+        }
+        (Some(timing), _) => timing.to_string(),
+        (None, _) => "-".into(),
+    }
+}
+
+fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let revision = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!revision.is_empty()).then_some(revision)
+}
+
+/// Identifies the machine a run was taken on. Can be overridden with the `AOC_MACHINE` environment variable.
+fn machine_id() -> String {
+    env::var("AOC_MACHINE")
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Converts days since the unix epoch into a (year, month, day) date.
+/// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "revision".into(),
+            match &value.revision {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert("timings".into(), JsonValue::from(value.timings.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let revision = json
+            .get("revision")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.revision to be null or string.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let machine = json
+            .get("machine")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.machine to be a string.")?;
+
+        let timings = json
+            .get("timings")
+            .ok_or("Expected entry to have key `timings`.")
+            .map_err(String::from)
+            .and_then(Timings::try_from)?;
+
+        Ok(HistoryEntry {
+            revision: revision.cloned(),
+            timestamp,
+            machine: machine.clone(),
+            timings,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_lines, report, HistoryEntry};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+    use tinyjson::JsonValue;
+
+    fn entry(timestamp: u64, part_1: f64, part_2: Option<f64>) -> HistoryEntry {
+        HistoryEntry {
+            revision: Some("abc1234".into()),
+            timestamp,
+            machine: "laptop".into(),
+            timings: Timings {
+                data: vec![Timing {
+                    day: day!(5),
+                    part_1: Some(PartTiming::from_nanos(part_1, 10)),
+                    part_2: part_2.map(|x| PartTiming::from_nanos(x, 10)),
+                    total_nanos: part_1 + part_2.unwrap_or_default(),
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let line = JsonValue::from(&entry(1_733_011_200, 1e6, None))
+            .stringify()
+            .unwrap();
+        let entries = parse_lines(&format!("{line}\n{line}\n")).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].revision, Some("abc1234".into()));
+        assert_eq!(entries[0].timings.data[0].part_nanos(1), Some(1e6));
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(entry(0, 0.0, None).date(), "1970-01-01");
+        assert_eq!(entry(1_733_011_200, 0.0, None).date(), "2024-12-01");
+        assert_eq!(entry(1_709_164_800, 0.0, None).date(), "2024-02-29");
+    }
+
+    #[test]
+    fn reports_changes_per_part() {
+        let entries = [
+            entry(1_733_011_200, 2e6, None),
+            entry(1_733_097_600, 1e6, Some(4e6)),
+        ];
+        let report = report(&entries, day!(5));
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[2].contains("2.0ms"));
+        assert!(lines[3].contains("1.0ms (-50.0%)"));
+        assert!(lines[3].contains("4.0ms"));
+    }
+
+    #[test]
+    fn reports_missing_days() {
+        let report = report(&[entry(0, 1e6, None)], day!(6));
+        assert!(report.ends_with("No stored timings."));
+    }
+}
@@ -0,0 +1,185 @@
+/// Moves data kept directly in `data/` before every year got its own directory, e.g. `data/inputs/01.txt`, into the
+/// directory of the default year, e.g. `data/2024/inputs/01.txt`.
+///
+/// Inputs, answers and timings are gitignored, so updating the template does not move them. Without the migration,
+/// commands would report missing inputs and start a new timing history.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::template::Year;
+
+/// Directory all data was kept in, and that now holds one directory per year.
+const DATA_DIR: &str = "data";
+
+const LEGACY_DIRS: [&str; 3] = ["inputs", "examples", "puzzles"];
+
+const LEGACY_FILES: [&str; 4] = [
+    "answers.json",
+    "submissions.json",
+    "timings.json",
+    "timings_history.jsonl",
+];
+
+#[derive(Debug, PartialEq)]
+pub enum Migration {
+    Moved {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    /// The file was not moved because its new location already has content.
+    Kept {
+        from: PathBuf,
+        to: PathBuf,
+    },
+}
+
+/// Legacy data files below `root`, skipping placeholders like `.keep`.
+fn legacy_files(root: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = LEGACY_FILES
+        .iter()
+        .map(|name| root.join(name))
+        .filter(|path| path.is_file())
+        .collect();
+
+    for dir in LEGACY_DIRS {
+        let Ok(entries) = fs::read_dir(root.join(dir)) else {
+            continue;
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter(|path| path.file_name().is_some_and(|x| x != ".keep"))
+            .collect();
+        paths.sort_unstable();
+        files.extend(paths);
+    }
+
+    files
+}
+
+/// Move the legacy data files below `root` into the directory of `year`.
+/// Files are only moved if their new location does not exist yet or is empty.
+pub fn migrate_dir(root: &Path, year: Year) -> Result<Vec<Migration>, io::Error> {
+    let mut migrations = vec![];
+
+    for from in legacy_files(root) {
+        let relative = from.strip_prefix(root).unwrap_or(&from);
+        let to = root.join(year.to_string()).join(relative);
+
+        if fs::metadata(&to).is_ok_and(|m| m.len() > 0) {
+            migrations.push(Migration::Kept { from, to });
+            continue;
+        }
+
+        if let Some(dir) = to.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::rename(&from, &to)?;
+        migrations.push(Migration::Moved { from, to });
+    }
+
+    // only succeeds for directories that are empty now.
+    for dir in LEGACY_DIRS {
+        let _ = fs::remove_dir(root.join(dir));
+    }
+
+    Ok(migrations)
+}
+
+/// Migrate the legacy data into the directory of the default year and report every file that was moved or kept.
+/// Without a default year, the legacy files are only listed.
+///
+/// Does nothing unless `data/inputs` exists. It is removed once all inputs are moved, so the migration runs once.
+pub fn migrate(year: Option<Year>) {
+    let root = Path::new(DATA_DIR);
+
+    if !root.join("inputs").is_dir() {
+        return;
+    }
+
+    let Some(year) = year else {
+        for path in legacy_files(root) {
+            eprintln!(
+                "Warning: \"{}\" is no longer read, data is kept per year now, e.g. in \"{DATA_DIR}/<year>/\".",
+                path.display()
+            );
+        }
+        return;
+    };
+
+    let migrations = match migrate_dir(root, year) {
+        Ok(migrations) => migrations,
+        Err(e) => {
+            eprintln!("Failed to move data to \"{DATA_DIR}/{year}\": {e}");
+            return;
+        }
+    };
+
+    for migration in migrations {
+        match migration {
+            Migration::Moved { from, to } => {
+                println!("Moved \"{}\" to \"{}\"", from.display(), to.display());
+            }
+            Migration::Kept { from, to } => eprintln!(
+                "Warning: \"{}\" is no longer read and was not moved because \"{}\" exists. Merge them by hand.",
+                from.display(),
+                to.display()
+            ),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{migrate_dir, Migration};
+    use crate::template::Year;
+    use std::{env, fs, process};
+
+    #[test]
+    fn moves_legacy_data_into_year() {
+        let root = env::temp_dir().join(format!("aoc-legacy-data-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::create_dir_all(root.join("2024/inputs")).unwrap();
+        fs::write(root.join("inputs/.keep"), "").unwrap();
+        fs::write(root.join("inputs/01.txt"), "1\n").unwrap();
+        fs::write(root.join("inputs/02.txt"), "2\n").unwrap();
+        fs::write(root.join("2024/inputs/02.txt"), "").unwrap();
+        fs::write(root.join("timings.json"), "{}").unwrap();
+        fs::write(root.join("2024/timings.json"), "{\"data\":[]}").unwrap();
+
+        let migrations = migrate_dir(&root, Year::new(2024).unwrap()).unwrap();
+
+        assert_eq!(
+            migrations,
+            [
+                Migration::Kept {
+                    from: root.join("timings.json"),
+                    to: root.join("2024/timings.json")
+                },
+                Migration::Moved {
+                    from: root.join("inputs/01.txt"),
+                    to: root.join("2024/inputs/01.txt")
+                },
+                Migration::Moved {
+                    from: root.join("inputs/02.txt"),
+                    to: root.join("2024/inputs/02.txt")
+                },
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2024/inputs/02.txt")).unwrap(),
+            "2\n"
+        );
+        assert!(root.join("timings.json").exists());
+        assert!(root.join("inputs/.keep").exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -2,14 +2,31 @@ use std::{env, fs};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod legacy_data;
+pub mod registry;
 pub mod runner;
 
+pub use answer::Answer;
 pub use day::*;
+pub use run_multi::RunOptions;
+pub use selection::*;
+pub use year::*;
 
+mod answer;
+mod answers;
 mod day;
+mod history;
+mod ocr;
+mod puzzle;
 mod readme_benchmarks;
+mod results;
 mod run_multi;
+mod selection;
+mod submissions;
+mod summary;
 mod timings;
+mod validation;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -17,28 +34,43 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, id: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(id.data_file(folder));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, id: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(id.year.data_dir())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", id.day));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads one of several named text files of a day to string. E.g. like `01/alice.txt`.
+#[must_use]
+pub fn read_file_named(folder: &str, id: PuzzleId, name: &str) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(id.year.data_dir())
+        .join(folder)
+        .join(id.day.to_string())
+        .join(format!("{name}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
+/// `DAY` is a [`PuzzleId`]. Its year is taken from the binary name (e.g. `2024-01`), falling back to `AOC_YEAR`.
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The generated `main` reads the input selected on the command line, e.g. `--example`, see [`runner::InputSource`].
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -52,8 +84,12 @@ macro_rules! solution {
     };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        /// The current puzzle.
+        const DAY: $crate::template::PuzzleId = $crate::template::PuzzleId::__from_bin_name(
+            env!("CARGO_BIN_NAME"),
+            option_env!("AOC_YEAR"),
+            $crate::day!($day),
+        );
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,7 +97,7 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+            let input = read_input(DAY);
             $( run_part($func, &input, DAY, $part); )*
         }
     };
@@ -0,0 +1,145 @@
+/// Recognizes letters drawn with `#` in the 6 pixel high font that many puzzles use for their answers.
+///
+/// Every letter is 4 pixels wide and followed by one empty column. Any character other than `#` is an unlit pixel.
+/// Glyphs of the known letters are listed below, rows separated by `\n`.
+const GLYPHS: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...\n#...\n.#.#\n..#.\n..#.\n..#."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const HEIGHT: usize = 6;
+const WIDTH: usize = 4;
+
+/// Decode the letters drawn in `text`. Returns `None` unless every glyph is a known letter.
+/// Empty rows around the letters and empty columns in front of them are ignored.
+pub fn decode(text: &str) -> Option<String> {
+    let mut rows: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+
+    while rows.last().is_some_and(|row| !row.contains(&true)) {
+        rows.pop();
+    }
+    let first = rows.iter().position(|row| row.contains(&true))?;
+    let rows = &rows[first..];
+
+    if rows.len() != HEIGHT {
+        return None;
+    }
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let pixel = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let first_column = (0..width).find(|x| (0..HEIGHT).any(|y| pixel(*x, y)))?;
+
+    // `I` and `Y` start with an empty column, the letters may start one column before the first lit pixel.
+    [first_column, first_column.saturating_sub(1)]
+        .into_iter()
+        .find_map(|start| decode_from(&pixel, start, width))
+}
+
+fn decode_from(
+    pixel: &impl Fn(usize, usize) -> bool,
+    start: usize,
+    width: usize,
+) -> Option<String> {
+    let mut letters = String::new();
+
+    for x in (start..width).step_by(WIDTH + 1) {
+        let glyph: Vec<String> = (0..HEIGHT)
+            .map(|y| {
+                (x..x + WIDTH)
+                    .map(|x| if pixel(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        // the column between two letters is empty.
+        if (0..HEIGHT).any(|y| pixel(x + WIDTH, y)) {
+            return None;
+        }
+
+        let glyph = glyph.join("\n");
+        if glyph.chars().all(|c| c != '#') {
+            continue;
+        }
+
+        let (letter, _) = GLYPHS.iter().find(|(_, g)| *g == glyph)?;
+        letters.push(*letter);
+    }
+
+    (!letters.is_empty()).then_some(letters)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode, GLYPHS};
+
+    /// Draw `letters` like a puzzle would, using `lit` and `unlit` pixels.
+    fn draw(letters: &str, lit: char, unlit: char) -> String {
+        let glyphs: Vec<Vec<&str>> = letters
+            .chars()
+            .map(|c| {
+                let (_, glyph) = GLYPHS.iter().find(|(l, _)| *l == c).unwrap();
+                glyph.lines().collect()
+            })
+            .collect();
+
+        (0..6)
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|g| format!("{}.", g[y]))
+                    .collect::<String>()
+                    .replace('#', &lit.to_string())
+                    .replace('.', &unlit.to_string())
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn decodes_letters() {
+        assert_eq!(decode(&draw("FGHJ", '#', '.')), Some("FGHJ".into()));
+        assert_eq!(decode(&draw("BUCACBUZ", '#', ' ')), Some("BUCACBUZ".into()));
+    }
+
+    #[test]
+    fn decodes_letters_with_margins() {
+        let text = format!("\n{}\n\n", draw("YLKI", '#', '.'));
+        assert_eq!(decode(&text), Some("YLKI".into()));
+
+        let text = draw("PERZ", '#', '.')
+            .lines()
+            .map(|line| format!("..{line}"))
+            .collect::<Vec<String>>()
+            .join("\n");
+        assert_eq!(decode(&text), Some("PERZ".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_glyphs() {
+        assert_eq!(decode("#\n#\n#\n#\n#\n#"), None);
+        assert_eq!(decode("####\n####"), None);
+        assert_eq!(decode("42"), None);
+        assert_eq!(decode(""), None);
+    }
+}
@@ -0,0 +1,452 @@
+/// Renders puzzle descriptions, as cached by `download` and `read`, to Markdown or to styled terminal output.
+/// Only the handful of elements used on adventofcode.com puzzle pages are supported.
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Markdown,
+    Terminal,
+}
+
+/// Converts the `<article>` HTML of a puzzle into Markdown.
+pub fn to_markdown(html: &str) -> String {
+    render(html, Target::Markdown)
+}
+
+/// Converts the `<article>` HTML of a puzzle into text with ANSI styles for printing.
+/// Emphasized text is printed bold, code italic.
+pub fn to_terminal(html: &str) -> String {
+    render(html, Target::Terminal)
+}
+
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>').map(|x| x + start) else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else if !tag.starts_with('!') {
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open {
+                name: name.to_lowercase(),
+                attrs,
+            });
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    tokens
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn get_attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')? + start;
+    Some(&attrs[start..end])
+}
+
+/// Collapses runs of whitespace outside of `<pre>` blocks like a browser would.
+fn collapse_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut last_was_space = false;
+
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                out.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            out.push(c);
+            last_was_space = false;
+        }
+    }
+
+    out
+}
+
+fn render(html: &str, target: Target) -> String {
+    // emphasized code (e.g. the answer of an example) is rendered as emphasis around code so Markdown picks it up.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut out = String::new();
+    let mut pre_depth = 0;
+    let mut links: Vec<String> = vec![];
+
+    let md = target == Target::Markdown;
+
+    for token in tokenize(&html) {
+        match token {
+            Token::Text(text) => {
+                if pre_depth > 0 {
+                    let text = decode_entities(text);
+                    if md {
+                        out.push_str(&text);
+                    } else {
+                        out.push_str(&text.replace('\n', "\n    "));
+                    }
+                } else {
+                    let text = collapse_whitespace(&decode_entities(text));
+                    // avoid leading spaces at the start of a block.
+                    if out.is_empty() || out.ends_with('\n') {
+                        out.push_str(text.trim_start());
+                    } else {
+                        out.push_str(&text);
+                    }
+                }
+            }
+            Token::Open { name, attrs } => match name.as_str() {
+                "h2" => out.push_str(if md { "## " } else { ANSI_BOLD }),
+                "pre" => {
+                    pre_depth += 1;
+                    out.push_str(if md { "```\n" } else { "    " });
+                }
+                "li" => out.push_str(if md { "- " } else { "  • " }),
+                "em" if pre_depth == 0 => out.push_str(if md { "*" } else { ANSI_BOLD }),
+                "code" if pre_depth == 0 => out.push_str(if md { "`" } else { ANSI_ITALIC }),
+                "a" if md => {
+                    links.push(get_attr(attrs, "href").unwrap_or_default().into());
+                    out.push('[');
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" | "p" => {
+                    if !md && name == "h2" {
+                        out.push_str(ANSI_RESET);
+                    }
+                    out.push_str("\n\n");
+                }
+                "pre" => {
+                    pre_depth -= 1;
+                    let trimmed = out.trim_end_matches([' ', '\n']).len();
+                    out.truncate(trimmed);
+                    out.push_str(if md { "\n```\n\n" } else { "\n\n" });
+                }
+                "li" => out.push('\n'),
+                "ul" | "ol" => out.push('\n'),
+                "em" if pre_depth == 0 => out.push_str(if md { "*" } else { ANSI_RESET }),
+                "code" if pre_depth == 0 => out.push_str(if md { "`" } else { ANSI_RESET }),
+                "a" if md => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                "article" => out.push('\n'),
+                _ => {}
+            },
+        }
+    }
+
+    let mut lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    lines.dedup_by(|a, b| a.is_empty() && b.is_empty());
+    lines.join("\n").trim().to_string() + "\n"
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Example inputs found in a puzzle description.
+#[derive(Debug, PartialEq)]
+pub struct Examples {
+    pub part_one: Option<String>,
+    /// Only set if part two introduces an example that differs from the one of part one.
+    pub part_two: Option<String>,
+}
+
+/// Picks the most likely example input for each part from the fenced code blocks of a Markdown puzzle description.
+///
+/// A block directly preceded by a paragraph mentioning "example" is preferred, otherwise the largest block is used.
+/// Part two usually reuses the first example and shows intermediate states "from above", so only blocks that are
+/// introduced as a new example are considered there.
+pub fn extract_examples(markdown: &str) -> Examples {
+    let (part_one, part_two) = split_parts(markdown);
+
+    let part_one = pick_example(part_one, true);
+    let part_two = pick_example(part_two, false).filter(|x| Some(x) != part_one.as_ref());
+
+    Examples { part_one, part_two }
+}
+
+/// Finds the expected answer of the example for each part of a Markdown puzzle description.
+///
+/// The answer is the last emphasized number of the part, e.g. "the total distance is *`11`*".
+pub fn extract_answers(markdown: &str) -> [Option<i128>; 2] {
+    let (part_one, part_two) = split_parts(markdown);
+    [part_one, part_two].map(|part| {
+        emphasized_spans(part)
+            .iter()
+            .rev()
+            .find_map(|span| span.trim_matches('`').trim().parse().ok())
+    })
+}
+
+/// Returns the title of a Markdown puzzle description, e.g. "Day 1: Historian Hysteria".
+pub fn extract_title(markdown: &str) -> Option<String> {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- "))
+        .and_then(|line| line.strip_suffix(" ---"))
+        .map(str::to_string)
+}
+
+fn split_parts(markdown: &str) -> (&str, &str) {
+    markdown
+        .split_once("## --- Part Two ---")
+        .unwrap_or((markdown, ""))
+}
+
+/// Collects the text between pairs of `*` outside of code spans and blocks.
+fn emphasized_spans(markdown: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block {
+            continue;
+        }
+
+        let mut in_code = false;
+        let mut current: Option<String> = None;
+
+        for c in line.chars() {
+            match c {
+                '`' => in_code = !in_code,
+                '*' if !in_code => {
+                    if let Some(span) = current.take() {
+                        spans.push(span);
+                    } else {
+                        current = Some(String::new());
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            if let Some(span) = current.as_mut() {
+                span.push(c);
+            }
+        }
+    }
+
+    spans
+}
+
+fn pick_example(markdown: &str, fallback_to_largest: bool) -> Option<String> {
+    // (text of the paragraph before the block, content of the block)
+    let mut blocks: Vec<(String, String)> = vec![];
+    let mut lines = markdown.lines();
+    let mut paragraph = String::new();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            let content: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.starts_with("```"))
+                .collect();
+            blocks.push((paragraph.to_lowercase(), content.join("\n") + "\n"));
+            paragraph.clear();
+        } else if !line.trim().is_empty() {
+            line.clone_into(&mut paragraph);
+        }
+    }
+
+    blocks
+        .iter()
+        .find(|(paragraph, _)| paragraph.contains("example") && !paragraph.contains("above"))
+        .or_else(|| {
+            blocks
+                .iter()
+                .filter(|_| fallback_to_largest)
+                .max_by_key(|(_, block)| block.lines().count())
+        })
+        .map(|(_, block)| block.clone())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_answers, extract_examples, extract_title, to_markdown, to_terminal};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const PUZZLE: &str = r#"<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present.
+For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul>
+<li>The smallest number is <code>1</code>.</li>
+<li>See <a href="/2024/about">about</a> &amp; more.</li>
+</ul>
+<p>In the example above, this is <code><em>11</em></code>.</p>
+</article>"#;
+
+    #[test]
+    fn renders_markdown() {
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "The *Chief Historian* is always present. For example:",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "```",
+            "",
+            "- The smallest number is `1`.",
+            "- See [about](/2024/about) & more.",
+            "",
+            "In the example above, this is *`11`*.",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(to_markdown(PUZZLE), expected);
+    }
+
+    #[test]
+    fn renders_terminal_output() {
+        let rendered = to_terminal(PUZZLE);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            format!("{ANSI_BOLD}--- Day 1: Historian Hysteria ---{ANSI_RESET}")
+        );
+        assert_eq!(
+            lines[2],
+            format!("The {ANSI_BOLD}Chief Historian{ANSI_RESET} is always present. For example:")
+        );
+        assert_eq!(lines[4], "    3   4");
+        assert_eq!(lines[5], "    4   3");
+        assert_eq!(
+            lines[7],
+            format!("  • The smallest number is {ANSI_ITALIC}1{ANSI_RESET}.")
+        );
+        assert_eq!(lines[8], "  • See about & more.");
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let markdown = [
+            "## --- Day 9 ---",
+            "",
+            "Something like `12345`:",
+            "",
+            "```",
+            "0..111....22222",
+            "```",
+            "",
+            "For example, suppose you have the following list:",
+            "",
+            "```",
+            "2333133121414131402",
+            "```",
+            "",
+            "## --- Part Two ---",
+            "",
+            "The first example from above now proceeds differently:",
+            "",
+            "```",
+            "00...111...2...333.44.5555.6666.777.888899",
+            "009..111...2...333.44.5555.6666.777.88889.",
+            "```",
+        ]
+        .join("\n");
+
+        let examples = extract_examples(&markdown);
+        assert_eq!(examples.part_one, Some("2333133121414131402\n".into()));
+        assert_eq!(examples.part_two, None);
+    }
+
+    #[test]
+    fn extracts_answers() {
+        let markdown = [
+            "Adding up (`2*4 + 5*5`) produces *`33`*.",
+            "",
+            "*What do you get?*",
+            "",
+            "## --- Part Two ---",
+            "",
+            "```",
+            "*1*",
+            "```",
+            "",
+            "The *first* example now produces *`1928`*, the second *-7*.",
+        ]
+        .join("\n");
+
+        assert_eq!(extract_answers(&markdown), [Some(33), Some(-7)]);
+        assert_eq!(extract_answers(&to_markdown(PUZZLE)), [Some(11), None]);
+    }
+
+    #[test]
+    fn extracts_title() {
+        assert_eq!(
+            extract_title(&to_markdown(PUZZLE)),
+            Some("Day 1: Historian Hysteria".into())
+        );
+        assert_eq!(extract_title("Nothing here"), None);
+    }
+
+    #[test]
+    fn extracts_distinct_part_two_examples() {
+        let markdown = "For example:\n\n```\n1\n```\n\n## --- Part Two ---\n\nHere is a larger example:\n\n```\n2\n3\n```\n";
+        let examples = extract_examples(markdown);
+        assert_eq!(examples.part_one, Some("1\n".into()));
+        assert_eq!(examples.part_two, Some("2\n3\n".into()));
+    }
+
+    #[test]
+    fn falls_back_to_largest_block() {
+        let markdown = "Look:\n\n```\na\n```\n\nAnd:\n\n```\nb\nc\n```\n";
+        let examples = extract_examples(markdown);
+        assert_eq!(examples.part_one, Some("b\nc\n".into()));
+        assert_eq!(examples.part_two, None);
+    }
+
+    #[test]
+    fn skips_repeated_examples() {
+        let markdown =
+            "For example:\n\n```\n1\n```\n\n## --- Part Two ---\n\nFor example:\n\n```\n1\n```\n";
+        let examples = extract_examples(markdown);
+        assert_eq!(examples.part_one, Some("1\n".into()));
+        assert_eq!(examples.part_two, None);
+    }
+}
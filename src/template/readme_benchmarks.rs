@@ -1,9 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// Every year has its own section in the table, storing timings of one year keeps the sections of other years.
+use std::collections::BTreeMap;
 use std::{fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +27,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,11 +49,10 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_section(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
         header,
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
@@ -64,35 +60,81 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = PuzzleId::new(year, timing.day).bin_path();
         lines.push(format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_1.map_or_else(|| "-".into(), |t| t.to_string()),
+            timing.part_2.map_or_else(|| "-".into(), |t| t.to_string())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+/// Splits the table into the sections of each year, keyed by the year in their header.
+/// Content before the first section, e.g. a table without a year, is dropped.
+fn parse_sections(table: &str, prefix: &str) -> BTreeMap<String, String> {
+    let mut sections: BTreeMap<String, String> = BTreeMap::new();
+    let mut current: Option<String> = None;
+
+    for line in table.lines() {
+        let year = line
+            .strip_prefix(prefix)
+            .and_then(|x| x.trim().strip_suffix("Benchmarks"))
+            .map(str::trim)
+            .filter(|x| !x.is_empty());
+
+        if let Some(year) = year {
+            current = Some(year.to_string());
+        }
+
+        if let Some(section) = current
+            .as_ref()
+            .map(|y| sections.entry(y.clone()).or_default())
+        {
+            section.push_str(line);
+            section.push('\n');
+        }
+    }
+
+    sections
+        .into_iter()
+        .map(|(year, section)| (year, section.trim_end().to_string()))
+        .collect()
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = s[positions.pos_start..positions.pos_end].replace(MARKER, "");
+
+    let mut sections = parse_sections(&table, "##");
+    sections.insert(
+        year.to_string(),
+        construct_section("##", year, timings, total_millis),
+    );
+
+    let sections: Vec<String> = sections.into_values().collect();
+    let table = format!("{MARKER}\n{}\n{MARKER}", sections.join("\n\n"));
+
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,27 +142,41 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::{
+            timings::{PartTiming, Timing, Timings},
+            Year,
+        },
+    };
+
+    fn year() -> Year {
+        Year::new(2024).unwrap()
+    }
+
+    fn ms(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming::from_nanos(millis * 1e6, 10))
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: ms(10.0),
+                    part_2: ms(20.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: ms(30.0),
+                    part_2: ms(40.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    part_1: ms(40.0),
+                    part_2: ms(50.0),
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,47 +187,68 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn keeps_benchmarks_of_other_years() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER, MARKER);
+        let other_year = Year::new(2015).unwrap();
+
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, other_year, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year(), get_mock_timings(), 100.0).unwrap();
+
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("## 2015 Benchmarks").count(), 1);
+        assert_eq!(s.matches("## 2024 Benchmarks").count(), 1);
+        assert!(s.find("## 2015").unwrap() < s.find("## 2024").unwrap());
+        assert!(s.contains("[Day 1](./src/bin/2015-01.rs)"));
+        assert!(s.contains("**Total: 190.00ms**\n\n## 2024 Benchmarks"));
+        assert!(s.contains("**Total: 100.00ms**\n<!--- benchmarking table --->\nbaz"));
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year(), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "## 2024 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
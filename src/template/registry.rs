@@ -0,0 +1,130 @@
+/// Links all solutions into one binary.
+///
+/// `build.rs` generates a list of every solution in `src/bin/<year>-<day>.rs`, which the `solutions` binary links
+/// into a single executable. This avoids building one binary per day in `cargo all` and `cargo time`.
+///
+/// `run_multi` starts this binary once for every part, so a looping part can be killed once its time is up and a
+/// crashing part does not take down the remaining days. Panics are caught and reported by the binary itself.
+use std::{env, process, thread};
+
+use crate::template::results::{PartResult, PartStatus};
+use crate::template::runner::{run_part, selected_part, InputSource};
+use crate::template::{PuzzleId, Year};
+
+/// Solutions expect the stack size of the main thread (8 MiB on most platforms), leave some room for recursion.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Signature of a registered solution part. Answers are converted to strings so all days share one type.
+pub type SolverFn = fn(&str) -> Option<String>;
+
+/// A solution linked into the `solutions` binary.
+pub struct Solution {
+    pub id: PuzzleId,
+    pub part_one: Option<SolverFn>,
+    pub part_two: Option<SolverFn>,
+}
+
+impl Solution {
+    /// The parts this solution implements.
+    pub fn parts(&self) -> Vec<u8> {
+        [(1, self.part_one), (2, self.part_two)]
+            .into_iter()
+            .filter(|(_, solver)| solver.is_some())
+            .map(|(part, _)| part)
+            .collect()
+    }
+}
+
+/// Entry point of the `solutions` binary.
+/// Usage: `solutions --year <year> [--time] [--part <part>] <day>`.
+///
+/// With `--list`, the parts of every solution of the year are printed instead, one day per line, e.g. `01 1 2`.
+pub fn run(solutions: &[Solution]) {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let year = args
+        .iter()
+        .position(|x| x == "--year")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|x| x.parse::<Year>().ok())
+        .or_else(Year::from_env)
+        .unwrap_or_else(|| {
+            eprintln!("No year specified, pass `--year` or set `AOC_YEAR`.");
+            process::exit(1);
+        });
+
+    if args.iter().any(|x| x == "--list") {
+        for solution in solutions.iter().filter(|s| s.id.year == year) {
+            let parts: Vec<String> = solution.parts().iter().map(ToString::to_string).collect();
+            println!("{} {}", solution.id.day, parts.join(" "));
+        }
+        return;
+    }
+
+    // skip the values of options, `--part 2` does not select day 2.
+    let day = args
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i == 0 || !matches!(args[i - 1].as_str(), "--year" | "--part"))
+        .find_map(|(_, x)| x.parse().ok())
+        .unwrap_or_else(|| {
+            eprintln!("No day specified.");
+            process::exit(1);
+        });
+
+    let id = PuzzleId::new(year, day);
+    match solutions.iter().find(|s| s.id == id) {
+        Some(solution) => run_solution(solution),
+        None => println!("Not solved."),
+    }
+}
+
+/// The input has been checked by `run_multi` already, see `template::validation`.
+fn run_solution(solution: &Solution) {
+    let input = match InputSource::Default.read(solution.id) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if let Some(part_one) = solution.part_one {
+        run_isolated(part_one, &input, solution.id, 1);
+    }
+    if let Some(part_two) = solution.part_two {
+        run_isolated(part_two, &input, solution.id, 2);
+    }
+}
+
+/// Run a part on its own thread and report it as panicked if the thread panics.
+///
+/// The part is reported as running before it starts, so `run_multi` can tell which part crashed or timed out.
+fn run_isolated(solver: SolverFn, input: &str, id: PuzzleId, part: u8) {
+    if selected_part().is_some_and(|selected| selected != part) {
+        return;
+    }
+
+    emit(id, part, PartStatus::Running);
+
+    let finished = thread::scope(|scope| {
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || run_part(solver, input, id, part))
+            .unwrap()
+            .join()
+    });
+
+    // the panic message is printed by the default hook.
+    if finished.is_err() {
+        println!("\rPart {part}: ✖ {}", PartStatus::Panicked);
+        emit(id, part, PartStatus::Panicked);
+    }
+}
+
+/// Report a part that did not return an answer (yet).
+fn emit(id: PuzzleId, part: u8, status: PartStatus) {
+    if let Err(e) = PartResult::placeholder(id.day, part, status).emit() {
+        eprintln!("Failed to write machine-readable result: {e}");
+    }
+}
@@ -0,0 +1,361 @@
+/// Machine-readable side channel between solution binaries and the runner.
+///
+/// When the environment variable [`RESULTS_FILE_ENV`] is set, every solution part appends one JSON line
+/// describing its result to that file. `run_multi` reads these lines instead of parsing the human-readable output.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// How running a single part ended.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PartStatus {
+    /// The part was started but has not reported back. If the process exited, it crashed while running the part.
+    Running,
+    Solved,
+    /// The solution returned `None`.
+    Unsolved,
+    Panicked,
+    TimedOut,
+    /// The process exited while running the part, e.g. on a stack overflow.
+    Crashed,
+    /// The part did not run because the input of the day is missing.
+    MissingInput,
+    /// The part did not run because the input of the day has a fatal issue, see `template::validation`.
+    InvalidInput,
+}
+
+impl PartStatus {
+    /// Status of a part that returned, depending on whether it produced an answer.
+    pub fn from_answer<T>(answer: Option<T>) -> Self {
+        match answer {
+            Some(_) => PartStatus::Solved,
+            None => PartStatus::Unsolved,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Running => "running",
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Panicked => "panicked",
+            PartStatus::TimedOut => "timed_out",
+            PartStatus::Crashed => "crashed",
+            PartStatus::MissingInput => "missing_input",
+            PartStatus::InvalidInput => "invalid_input",
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            PartStatus::Running => "running",
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "no answer",
+            PartStatus::Panicked => "panicked",
+            PartStatus::TimedOut => "timed out",
+            PartStatus::Crashed => "crashed",
+            PartStatus::MissingInput => "missing input",
+            PartStatus::InvalidInput => "invalid input",
+        };
+        write!(f, "{status}")
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            PartStatus::Running,
+            PartStatus::Solved,
+            PartStatus::Unsolved,
+            PartStatus::Panicked,
+            PartStatus::TimedOut,
+            PartStatus::Crashed,
+            PartStatus::MissingInput,
+            PartStatus::InvalidInput,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == s)
+        .ok_or_else(|| format!("unknown part status `{s}`."))
+    }
+}
+
+/// Represents the outcome of running a single part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: PartStatus,
+    pub nanos: f64,
+    pub samples: u128,
+    pub median_nanos: Option<f64>,
+    pub p95_nanos: Option<f64>,
+}
+
+impl PartResult {
+    /// Result of a part that did not return an answer (yet), e.g. because it is still running or was killed.
+    pub fn placeholder(day: Day, part: u8, status: PartStatus) -> Self {
+        PartResult {
+            day,
+            part,
+            answer: None,
+            status,
+            nanos: 0_f64,
+            samples: 0,
+            median_nanos: None,
+            p95_nanos: None,
+        }
+    }
+
+    /// Result of a part that returned `answer` after `nanos`, as reported by a solution.
+    #[cfg(feature = "test_lib")]
+    pub fn answered(day: Day, part: u8, answer: Option<&str>, nanos: f64) -> Self {
+        PartResult {
+            answer: answer.map(Into::into),
+            nanos,
+            samples: 1,
+            ..Self::placeholder(day, part, PartStatus::from_answer(answer))
+        }
+    }
+
+    /// Append this result to the file named by [`RESULTS_FILE_ENV`]. Does nothing if the variable is not set.
+    pub fn emit(&self) -> Result<(), Error> {
+        let Ok(path) = env::var(RESULTS_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| Error::other(e.to_string()))?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")
+    }
+
+    /// Read all results from a results file. If not present, returns no results.
+    /// Only the latest result of every part is kept, see [`PartResult::latest`].
+    pub fn read_all(path: &Path) -> Result<Vec<Self>, String> {
+        fs::read_to_string(path)
+            .map_or_else(|_| Ok(vec![]), |content| Self::parse_lines(&content))
+            .map(Self::latest)
+    }
+
+    /// Keep the latest result of every part, in order of their first appearance.
+    ///
+    /// A part reports [`PartStatus::Running`] before it starts and its outcome when done. A part that timed out
+    /// keeps running in the background, so a late outcome does not replace [`PartStatus::TimedOut`].
+    pub fn latest(results: Vec<Self>) -> Vec<Self> {
+        let mut latest: Vec<Self> = vec![];
+
+        for result in results {
+            match latest
+                .iter_mut()
+                .find(|r| r.day == result.day && r.part == result.part)
+            {
+                Some(r) if r.status == PartStatus::TimedOut => {}
+                Some(r) => *r = result,
+                None => latest.push(result),
+            }
+        }
+
+        latest
+    }
+
+    /// Parse results from newline-delimited JSON.
+    pub fn parse_lines(content: &str) -> Result<Vec<Self>, String> {
+        content
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let json = JsonValue::from_str(l).or(Err("not a valid JSON line."))?;
+                PartResult::try_from(&json)
+            })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        if let Some(median_nanos) = value.median_nanos {
+            map.insert("median_nanos".into(), JsonValue::Number(median_nanos));
+        }
+
+        if let Some(p95_nanos) = value.p95_nanos {
+            map.insert("p95_nanos".into(), JsonValue::Number(p95_nanos));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected result to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected result.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .filter(|x| *x == 1 || *x == 2)
+            .ok_or("Expected result.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .and_then(|s| s.parse().ok())
+            .ok_or("Expected result.status to be a part status.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected result.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u128)
+            .ok_or("Expected result.samples to be a number.")?;
+
+        let median_nanos = json
+            .get("median_nanos")
+            .and_then(|v| v.get::<f64>().copied());
+        let p95_nanos = json.get("p95_nanos").and_then(|v| v.get::<f64>().copied());
+
+        Ok(PartResult {
+            day,
+            part,
+            answer: answer.cloned(),
+            status,
+            nanos,
+            samples,
+            median_nanos,
+            p95_nanos,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartResult, PartStatus};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_results() {
+        let result = PartResult {
+            day: day!(3),
+            part: 2,
+            answer: Some("@ ( ) ms \"quoted\"\nsecond line".into()),
+            status: PartStatus::Solved,
+            nanos: 74.13,
+            samples: 100_000,
+            median_nanos: Some(70.0),
+            p95_nanos: None,
+        };
+
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+        assert_eq!(PartResult::parse_lines(&line).unwrap(), vec![result]);
+    }
+
+    #[test]
+    fn parses_multiple_lines() {
+        let content = [
+            r#"{ "day": "01", "part": 1, "answer": "10", "status": "solved", "nanos": 1000, "samples": 10 }"#,
+            "",
+            r#"{ "day": "01", "part": 2, "answer": null, "status": "unsolved", "nanos": 5, "samples": 1 }"#,
+        ]
+        .join("\n");
+
+        let results = PartResult::parse_lines(&content).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer, Some("10".into()));
+        assert_eq!(results[1].part, 2);
+        assert_eq!(results[1].answer, None);
+        assert_eq!(results[0].status, PartStatus::Solved);
+        assert_eq!(results[1].status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn keeps_latest_result_of_every_part() {
+        let content = [
+            r#"{ "day": "01", "part": 1, "answer": null, "status": "running", "nanos": 0, "samples": 0 }"#,
+            r#"{ "day": "01", "part": 1, "answer": "10", "status": "solved", "nanos": 5, "samples": 1 }"#,
+            r#"{ "day": "01", "part": 2, "answer": null, "status": "running", "nanos": 0, "samples": 0 }"#,
+            r#"{ "day": "01", "part": 2, "answer": null, "status": "timed_out", "nanos": 0, "samples": 0 }"#,
+            r#"{ "day": "01", "part": 2, "answer": "20", "status": "solved", "nanos": 5, "samples": 1 }"#,
+            r#"{ "day": "02", "part": 1, "answer": null, "status": "running", "nanos": 0, "samples": 0 }"#,
+        ]
+        .join("\n");
+
+        let results = PartResult::latest(PartResult::parse_lines(&content).unwrap());
+        let statuses: Vec<PartStatus> = results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                PartStatus::Solved,
+                PartStatus::TimedOut,
+                PartStatus::Running
+            ]
+        );
+        assert_eq!(results[0].answer, Some("10".into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_results() {
+        PartResult::parse_lines(r#"{ "day": "01", "part": 3 }"#).unwrap();
+    }
+}
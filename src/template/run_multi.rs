@@ -1,40 +1,82 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, time::Duration};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
+    results::{PartResult, PartStatus},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Time a part may take before its process is killed, unless `--timeout` is passed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
-    let mut need_space = false;
+/// Outcome of running a set of days.
+pub struct MultiRun {
+    /// Results of every part that was run.
+    pub results: Vec<PartResult>,
+    /// Timings of every day that was run, if timing was requested.
+    pub timings: Option<Timings>,
+}
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+/// How the solutions of `run_multi` are run.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    /// Only run this part of every day.
+    pub part: Option<u8>,
+    pub is_release: bool,
+    /// Number of days that run at once. Timed runs always run one day at a time.
+    pub jobs: usize,
+    /// Time a single part may take before its process is killed and the part is reported as timed out.
+    /// Timed runs allow for benching the part on top of that, see [`child_commands::deadline`].
+    pub timeout: Duration,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            part: None,
+            is_release: false,
+            jobs: 1,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+/// Runs the solutions of `days_to_run`. Days run in up to `jobs` processes at once, unless they are timed.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_timed: bool,
+    options: &RunOptions,
+) -> MultiRun {
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let results = if days.is_empty() {
+        vec![]
+    } else if options.jobs > 1 && !is_timed {
+        child_commands::run_solutions_parallel(year, &days, options).unwrap()
+    } else {
+        child_commands::run_solutions(year, &days, is_timed, options).unwrap()
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    let timings: Vec<Timing> = days
+        .iter()
+        // days whose input is missing or invalid did not run, they have no timings.
+        .filter(|day| {
+            results.iter().any(|r| {
+                r.day == **day
+                    && !matches!(
+                        r.status,
+                        PartStatus::MissingInput | PartStatus::InvalidInput
+                    )
+            })
+        })
+        .map(|day| child_commands::parse_exec_time(&results, *day))
+        .collect();
 
-    if is_timed {
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,14 +85,21 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
-    }
+    };
+
+    MultiRun { results, timings }
 }
 
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    /// The `solutions` binary could not be built.
+    Build,
+    /// The `solutions` binary could not list the solutions it contains.
+    List,
     IO(io::Error),
+    Results(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -59,76 +108,435 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// All solutions are linked into the `solutions` binary, see `template::registry`.
+/// This module encapsulates interaction with this binary, both invoking it as well as collecting the results.
+///
+/// Every part runs in its own process, which is killed once the part takes longer than its deadline. A part that
+/// crashes or times out only affects that part, the remaining parts and days are run as usual.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, RunOptions};
+    use crate::template::{
+        results::{PartResult, PartStatus, RESULTS_FILE_ENV},
+        runner::{bench_timeout, InputSource},
+        timings::PartTiming,
+        validation::{self, InputIssue},
+        Day, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, YEAR_ENV,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+        collections::{BTreeMap, HashMap},
+        env, fs,
+        io::{self, Read, Write},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::{mpsc, Mutex},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Longest pause between two checks whether a part is done.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Arguments of the `solutions` binary to run a single part, see `template::registry::run`.
+    fn solution_args(id: PuzzleId, part: u8, is_timed: bool) -> Vec<String> {
+        let mut args = vec![
+            "--year".to_string(),
+            id.year.to_string(),
+            "--part".to_string(),
+            part.to_string(),
+        ];
+
+        if is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".to_string());
+        }
+
+        args.push(id.day.to_string());
+        args
+    }
+
+    /// Time a part may take before its process is killed. Timed parts are benched after their first run, so they
+    /// may take as long as benching a part whose first run takes `timeout`.
+    pub fn deadline(timeout: Duration, is_timed: bool) -> Duration {
+        if is_timed {
+            bench_timeout(timeout)
+        } else {
+            timeout
+        }
+    }
+
+    /// Mark a part as `status` if it was still running when its process ended, or did not report anything.
+    /// Returns `false` if the part had finished already.
+    fn mark_failed(results: &mut Vec<PartResult>, day: Day, part: u8, status: PartStatus) -> bool {
+        match results.iter_mut().find(|r| r.day == day && r.part == part) {
+            Some(result) if result.status == PartStatus::Running => result.status = status,
+            Some(_) => return false,
+            None => results.push(PartResult::placeholder(day, part, status)),
+        }
+        true
+    }
+
+    /// Wait for `child` to exit. If it is still running after `timeout`, it is killed and `None` is returned.
+    fn wait_or_kill(child: &mut Child, timeout: Duration) -> Result<Option<ExitStatus>, io::Error> {
+        let deadline = Instant::now() + timeout;
+        let mut interval = Duration::from_millis(1);
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                child.kill()?;
+                child.wait()?;
+                return Ok(None);
+            }
+
+            // check often at first, most parts are done within a few milliseconds.
+            thread::sleep(interval.min(deadline - now));
+            interval = (interval * 2).min(POLL_INTERVAL);
+        }
+    }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Output of a day. Days that run in parallel buffer their output, so it can be printed in day order.
+    struct DayOutput {
+        buffered: bool,
+        stdout: Vec<u8>,
+        stderr: Vec<u8>,
+    }
+
+    impl DayOutput {
+        fn println(&mut self, line: &str) {
+            if self.buffered {
+                writeln!(self.stdout, "{line}").unwrap();
+            } else {
+                println!("{line}");
+            }
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        fn eprintln(&mut self, line: &str) {
+            if self.buffered {
+                writeln!(self.stderr, "{line}").unwrap();
+            } else {
+                eprintln!("{line}");
+            }
+        }
+    }
+
+    /// Output and results of running a single day.
+    struct DayRun {
+        output: DayOutput,
+        results: Vec<PartResult>,
+        elapsed: Duration,
+    }
+
+    /// Run the solutions of the given days one part at a time and collect the results they report.
+    pub fn run_solutions(
+        year: Year,
+        days: &[Day],
+        is_timed: bool,
+        options: &RunOptions,
+    ) -> Result<Vec<PartResult>, Error> {
+        let executable = build_solutions(options.is_release)?;
+        let parts = list_parts(&executable, year)?;
+        let mut results = vec![];
+
+        for (index, day) in days.iter().enumerate() {
+            if index > 0 {
+                println!();
+            }
+            let run = run_day(
+                &executable,
+                year,
+                *day,
+                parts.get(day),
+                is_timed,
+                options,
+                false,
+            )?;
+            results.extend(run.results);
+        }
+
+        Ok(results)
+    }
+
+    /// Run the solutions of the given days in up to `jobs` processes at once and collect the results they report.
+    /// The output of every day is buffered and printed in day order as soon as all previous days are done.
+    pub fn run_solutions_parallel(
+        year: Year,
+        days: &[Day],
+        options: &RunOptions,
+    ) -> Result<Vec<PartResult>, Error> {
+        let jobs = options.jobs;
+        let executable = build_solutions(options.is_release)?;
+        let parts = list_parts(&executable, year)?;
+        let queue = Mutex::new(days.iter().copied().enumerate());
+        let (sender, receiver) = mpsc::channel();
+        let timer = Instant::now();
+
+        let (results, sum_of_days) = thread::scope(|scope| {
+            for _ in 0..jobs.min(days.len()) {
+                let (sender, queue, executable, parts) =
+                    (sender.clone(), &queue, &executable, &parts);
+                scope.spawn(move || loop {
+                    let Some((index, day)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let run = run_day(executable, year, day, parts.get(&day), false, options, true);
+                    // the receiver only hangs up after an error, in which case the remaining days are irrelevant.
+                    let _ = sender.send((index, run));
+                });
+            }
+            drop(sender);
+
+            let mut pending = BTreeMap::new();
+            let mut next = 0;
+            let mut results = vec![];
+            let mut sum_of_days = Duration::ZERO;
+
+            for (index, run) in receiver {
+                pending.insert(index, run);
+
+                while let Some(run) = pending.remove(&next) {
+                    let run = run?;
+                    if next > 0 {
+                        println!();
+                    }
+                    io::stdout().write_all(&run.output.stdout)?;
+                    io::stderr().write_all(&run.output.stderr)?;
+
+                    results.extend(run.results);
+                    sum_of_days += run.elapsed;
+                    next += 1;
+                }
+            }
+
+            Ok::<_, Error>((results, sum_of_days))
+        })?;
+
+        println!(
+            "\n{ANSI_BOLD}Total (Wall):{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET} (sum of days: {:.2}ms, {jobs} jobs)",
+            timer.elapsed().as_secs_f64() * 1000.0,
+            sum_of_days.as_secs_f64() * 1000.0,
+        );
+
+        Ok(results)
+    }
+
+    /// Build the `solutions` binary once and return the path of the executable.
+    fn build_solutions(is_release: bool) -> Result<PathBuf, Error> {
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            "solutions",
+            "--message-format=json-render-diagnostics",
+        ];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(Error::Build);
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .find_map(|json| {
+                json.get::<HashMap<String, JsonValue>>()?
+                    .get("executable")?
+                    .get::<String>()
+                    .cloned()
+            })
+            .map(PathBuf::from)
+            .ok_or(Error::Build)
+    }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    /// The parts of every solution of `year` contained in the `solutions` binary.
+    fn list_parts(executable: &Path, year: Year) -> Result<HashMap<Day, Vec<u8>>, Error> {
+        let output = Command::new(executable)
+            .args(["--year", &year.to_string(), "--list"])
+            .stderr(Stdio::inherit())
+            .output()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        if !output.status.success() {
+            return Err(Error::List);
+        }
 
-        let mut output = vec![];
+        Ok(parse_parts(&String::from_utf8_lossy(&output.stdout)))
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    /// Parse the output of `solutions --list`, e.g. `01 1 2`.
+    fn parse_parts(list: &str) -> HashMap<Day, Vec<u8>> {
+        list.lines()
+            .filter_map(|line| {
+                let mut values = line.split_whitespace();
+                let day = values.next()?.parse().ok()?;
+                Some((day, values.filter_map(|x| x.parse().ok()).collect()))
+            })
+            .collect()
+    }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    /// Run every part of a day in its own process. Days without a solution are reported as not solved.
+    /// The input is checked once up front, so its issues are not reported by every part.
+    fn run_day(
+        executable: &Path,
+        year: Year,
+        day: Day,
+        parts: Option<&Vec<u8>>,
+        is_timed: bool,
+        options: &RunOptions,
+        buffered: bool,
+    ) -> Result<DayRun, Error> {
+        let timer = Instant::now();
+        let mut run = DayRun {
+            output: DayOutput {
+                buffered,
+                stdout: vec![],
+                stderr: vec![],
+            },
+            results: vec![],
+            elapsed: Duration::ZERO,
+        };
+
+        run.output
+            .println(&format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"));
+        run.output.println("------");
+
+        let Some(parts) = parts else {
+            run.output.println("Not solved.");
+            return Ok(run);
+        };
+
+        let parts: Vec<u8> = parts
+            .iter()
+            .copied()
+            .filter(|part| options.part.is_none_or(|selected| selected == *part))
+            .collect();
+
+        // parts that cannot run are reported, so the day shows up as failed.
+        let skip = |status| {
+            parts
+                .iter()
+                .map(|part| PartResult::placeholder(day, *part, status))
+                .collect()
+        };
+
+        let id = PuzzleId::new(year, day);
+        let input = match InputSource::Default.read(id) {
+            Ok(input) => input,
+            Err(e) => {
+                run.output.eprintln(&e.to_string());
+                run.results = skip(PartStatus::MissingInput);
+                return Ok(run);
+            }
+        };
+
+        let issues = validation::validate(&input);
+        for message in validation::messages(&issues, id) {
+            run.output.eprintln(&message);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        if issues.iter().any(InputIssue::is_fatal) {
+            run.results = skip(PartStatus::InvalidInput);
+            return Ok(run);
+        }
 
-        Ok(output)
+        for part in parts {
+            let results = run_part(executable, id, part, is_timed, options, &mut run.output)?;
+            run.results.extend(results);
+        }
+
+        run.elapsed = timer.elapsed();
+        Ok(run)
+    }
+
+    /// Collect everything written to a pipe of a child process on a separate thread.
+    fn read_pipe(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buffer = vec![];
+            let _ = pipe.read_to_end(&mut buffer);
+            buffer
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Run a single part in its own process and kill it once it exceeds its deadline.
+    fn run_part(
+        executable: &Path,
+        id: PuzzleId,
+        part: u8,
+        is_timed: bool,
+        options: &RunOptions,
+        output: &mut DayOutput,
+    ) -> Result<Vec<PartResult>, Error> {
+        // results are reported through a side channel file, see `template::results`.
+        let results_path =
+            env::temp_dir().join(format!("aoc-results-{}-{id}-{part}.jsonl", process::id()));
+        let _ = fs::remove_file(&results_path);
+
+        let stdio = || {
+            if output.buffered {
+                Stdio::piped()
+            } else {
+                Stdio::inherit()
+            }
+        };
+
+        let mut child = Command::new(executable)
+            .args(solution_args(id, part, is_timed))
+            .env(RESULTS_FILE_ENV, &results_path)
+            .env(YEAR_ENV, id.year.to_string())
+            .stdout(stdio())
+            .stderr(stdio())
+            .spawn()?;
+
+        let stdout = child.stdout.take().map(read_pipe);
+        let stderr = child.stderr.take().map(read_pipe);
+
+        let deadline = deadline(options.timeout, is_timed);
+        let status = wait_or_kill(&mut child, deadline)?;
+
+        if let Some(stdout) = stdout {
+            output.stdout.extend(stdout.join().unwrap());
+        }
+        if let Some(stderr) = stderr {
+            output.stderr.extend(stderr.join().unwrap());
+        }
+
+        let results = PartResult::read_all(&results_path).map_err(Error::Results);
+        let _ = fs::remove_file(&results_path);
+        let mut results = results?;
+
+        match status {
+            Some(status) if status.success() => {}
+            Some(status) => {
+                let message = if mark_failed(&mut results, id.day, part, PartStatus::Crashed) {
+                    format!("Day {} part {part} crashed the process ({status}).", id.day)
+                } else {
+                    format!("Day {} part {part} failed ({status}).", id.day)
+                };
+                output.eprintln(&message);
+            }
+            None => {
+                mark_failed(&mut results, id.day, part, PartStatus::TimedOut);
+                output.println(&format!("Part {part}: ✖ timed out after {deadline:.1?}"));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Collect the timings of all solved parts.
+    pub fn parse_exec_time(results: &[PartResult], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
@@ -136,59 +544,29 @@ pub mod child_commands {
             total_nanos: 0_f64,
         };
 
-        output
+        results
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+            .filter(|r| r.day == day && r.answer.is_some())
+            .for_each(|r| {
+                let timing = PartTiming {
+                    nanos: r.nanos,
+                    samples: r.samples,
+                    median_nanos: r.median_nanos,
+                    p95_nanos: r.p95_nanos,
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                if r.part == 1 {
+                    timings.part_1 = Some(timing);
+                } else {
+                    timings.part_2 = Some(timing);
                 }
 
-                timings.total_nanos += nanos;
+                timings.total_nanos += r.nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -205,47 +583,48 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{deadline, mark_failed, parse_exec_time, parse_parts};
 
-        use crate::day;
+        use crate::{
+            day,
+            template::results::{PartResult, PartStatus},
+        };
+        use std::time::Duration;
 
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    PartResult::answered(day!(1), 1, Some("0"), 74.0),
+                    PartResult::answered(day!(1), 2, Some("10"), 74_130_000.0),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap().to_string(), "74.0ns");
+            assert_eq!(res.part_2.unwrap().to_string(), "74.1ms");
         }
 
         #[test]
         fn parses_with_patterns_in_input() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    PartResult::answered(day!(1), 1, Some("@ @ @ ( ) ms"), 2_000_000_000.0),
+                    PartResult::answered(day!(1), 2, Some("10s (1ms @ 5 samples)"), 100_000_000.0),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap().to_string(), "2.0s");
+            assert_eq!(res.part_2.unwrap().to_string(), "100.0ms");
         }
 
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    PartResult::answered(day!(1), 1, None, 5.0),
+                    PartResult::answered(day!(1), 2, None, 5.0),
                 ],
                 day!(1),
             );
@@ -253,5 +632,38 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn marks_failed_parts() {
+            let mut results = vec![PartResult::answered(day!(1), 1, None, 0.0)];
+            results[0].status = PartStatus::Running;
+
+            assert!(mark_failed(&mut results, day!(1), 1, PartStatus::TimedOut));
+            assert_eq!(results[0].status, PartStatus::TimedOut);
+
+            assert!(mark_failed(&mut results, day!(1), 2, PartStatus::Crashed));
+            assert_eq!(results[1].part, 2);
+            assert_eq!(results[1].status, PartStatus::Crashed);
+
+            let mut results = vec![PartResult::answered(day!(1), 1, Some("10"), 5.0)];
+            assert!(!mark_failed(&mut results, day!(1), 1, PartStatus::Crashed));
+            assert_eq!(results[0].status, PartStatus::Solved);
+        }
+
+        #[test]
+        fn extends_deadline_for_benching() {
+            let timeout = Duration::from_secs(5);
+            assert_eq!(deadline(timeout, false), timeout);
+            // a part taking up to `timeout` is benched at least ten times.
+            assert!(deadline(timeout, true) > timeout * 12);
+        }
+
+        #[test]
+        fn parses_listed_parts() {
+            let parts = parse_parts("01 1 2\n02 1\n25 1\n");
+            assert_eq!(parts.len(), 3);
+            assert_eq!(parts[&day!(1)], [1, 2]);
+            assert_eq!(parts[&day!(2)], [1]);
+        }
     }
 }
@@ -2,34 +2,74 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
-use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{cmp, env, fs, io, process};
 
+use crate::template::answer::{frame, is_multi_line};
+use crate::template::answers::{Answers, Verification};
+use crate::template::results::{PartResult, PartStatus};
+use crate::template::submissions::{Submission, SubmissionOutcome, Submissions};
+use crate::template::validation;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, Answer, PuzzleId, Year, ANSI_ITALIC, ANSI_RESET};
+
+pub fn run_part<I: Copy, T: Answer>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    id: PuzzleId,
+    part: u8,
+) {
+    if selected_part().is_some_and(|selected| selected != part) {
+        return;
+    }
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats) = run_timed(func, input, |result| print_result(result, &part_str, ""));
+
+    let answer = result.as_ref().map(Answer::submission);
+    let verification = answer.as_deref().map_or(Verification::Unknown, |answer| {
+        check_answer(answer, id, part, &input_source())
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result,
+        &part_str,
+        &format!(
+            "{}{verification}",
+            format_duration(&stats.mean, stats.samples)
+        ),
+    );
+
+    if stats.samples > 1 {
+        print_stats(&stats);
+    }
+
+    let part_result = PartResult {
+        day: id.day,
+        part,
+        status: PartStatus::from_answer(answer.as_ref()),
+        answer,
+        nanos: stats.mean.as_nanos() as f64,
+        samples: stats.samples,
+        median_nanos: Some(stats.median.as_nanos() as f64),
+        p95_nanos: Some(stats.p95.as_nanos() as f64),
+    };
+
+    if let Err(e) = part_result.emit() {
+        eprintln!("Failed to write machine-readable result: {e}");
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, id, part);
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+fn run_timed<I: Copy, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, BenchStats) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,23 +81,41 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let stats = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(vec![base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Time spent collecting samples of a fast part when benching.
+const BENCH_TIME: Duration = Duration::from_secs(1);
+
+/// Number of samples collected when benching, even if a part is slow.
+const MIN_SAMPLES: u128 = 10;
+
+/// Upper bound of the time benching takes for a part whose first run takes at most `timeout`.
+/// Slow parts run `MIN_SAMPLES + 2` times (first run, warm up and samples), fast parts are sampled for about
+/// [`BENCH_TIME`] and warmed up for a tenth of it.
+pub fn bench_timeout(timeout: Duration) -> Duration {
+    timeout * (MIN_SAMPLES as u32 + 2) + BENCH_TIME * 2
+}
+
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
     let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        (BENCH_TIME.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(MIN_SAMPLES, 10000);
+
+    // warm up caches and branch predictors before collecting samples.
+    for _ in 0..(bench_iterations / 10).clamp(1, 100) {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,19 +125,95 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
+/// Summary of the samples collected while benching a solution part.
+/// Slow samples with a modified z-score above [`OUTLIER_THRESHOLD`] are rejected before computing the statistics.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of samples that were kept.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: usize,
+}
+
+/// Modified z-score above which a sample is considered an outlier (Iglewicz and Hoaglin).
+const OUTLIER_THRESHOLD: f64 = 3.5;
+
+/// Percentage of samples that may be rejected as outliers at most.
+const MAX_OUTLIER_PERCENT: usize = 10;
+
+impl BenchStats {
+    /// Computes statistics for a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let total = samples.len();
+        let samples = reject_outliers(samples);
+        let nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Self {
+            mean: Duration::from_secs_f64(mean / 1e9),
+            median: Duration::from_secs_f64(median(&nanos) / 1e9),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            p95: percentile(&samples, 95),
+            stddev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            samples: samples.len() as u128,
+            outliers: total - samples.len(),
+        }
+    }
+}
+
+/// Median of a sorted slice.
+fn median(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Nearest-rank percentile of a sorted slice.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+/// Drops slow samples whose distance to the median is large compared to the median absolute deviation (MAD).
+/// Runs are skewed towards slow samples, so fast samples are always kept and at most [`MAX_OUTLIER_PERCENT`]
+/// percent of the samples are dropped. If more than half of the samples are identical, e.g. with a coarse timer,
+/// the MAD is zero and every sample slower than the median counts as an outlier, within that limit.
+/// Expects `sorted` to be sorted and returns the kept samples in order.
+fn reject_outliers(mut sorted: Vec<Duration>) -> Vec<Duration> {
+    let nanos: Vec<f64> = sorted.iter().map(|x| x.as_nanos() as f64).collect();
+    let med = median(&nanos);
+
+    let mut deviations: Vec<f64> = nanos.iter().map(|x| (x - med).abs()).collect();
+    deviations.sort_unstable_by(f64::total_cmp);
+    let scale = median(&deviations) / 0.6745;
+
+    let max_outliers = (sorted.len() * MAX_OUTLIER_PERCENT).div_ceil(100);
+    let outliers = nanos
         .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+        .rev()
+        .take(max_outliers)
+        .take_while(|x| *x - med > OUTLIER_THRESHOLD * scale)
+        .count();
+
+    sorted.truncate(sorted.len() - outliers);
+    sorted
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -90,22 +224,42 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_stats(stats: &BenchStats) {
+    let outliers = match stats.outliers {
+        0 => String::new(),
+        1 => " · 1 outlier rejected".into(),
+        n => format!(" · {n} outliers rejected"),
+    };
+
+    println!(
+        "        {ANSI_ITALIC}median {:.1?} · min {:.1?} · max {:.1?} · p95 {:.1?} · σ {:.1?}{outliers}{ANSI_RESET}",
+        stats.median, stats.min, stats.max, stats.p95, stats.stddev
+    );
+}
+
+/// Multi-line answers are printed as a framed block below the part, along with the letters they draw if any.
+fn print_result<T: Answer>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+            let rendered = result.render();
+            if is_multi_line(&rendered) {
+                let submission = result.submission();
+                let str = if is_multi_line(&submission) {
+                    format!("{part}: ▼ {duration_str}")
+                } else {
+                    format!("{part}: ▼ {ANSI_BOLD}{submission}{ANSI_RESET}{duration_str}")
+                };
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
                     print!("\r");
                     println!("{str}");
-                    println!("{result}");
+                    println!("{}", frame(&rendered));
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str = format!("{part}: {ANSI_BOLD}{rendered}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -125,18 +279,188 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Input a solution runs on, as selected on the command line.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// The puzzle input, e.g. `data/2024/inputs/05.txt`.
+    Default,
+    /// An input passed with `--input <name>`, e.g. `data/2024/inputs/05/alice.txt`.
+    Named(String),
+    /// A file passed with `--input <path>`, e.g. `--input ~/Downloads/input.txt`.
+    Path(PathBuf),
+    /// Standard input, passed with `--input -`.
+    Stdin,
+    /// The example passed with `--example`, or the example of a part passed with `--example-part <part>`.
+    Example(Option<u8>),
+}
+
+impl InputSource {
+    /// Examples take precedence over `--input`.
+    /// An input containing a path separator or a file extension is read from that path, otherwise it names an input.
+    pub fn from_args(args: &[String]) -> Self {
+        let value = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)? + 1;
+            args.get(index).cloned()
+        };
+
+        if let Some(part) = value("--example-part").and_then(|x| x.parse().ok()) {
+            return InputSource::Example(Some(part));
+        }
+
+        if args.iter().any(|x| x == "--example") {
+            return InputSource::Example(None);
+        }
+
+        match value("--input") {
+            None => InputSource::Default,
+            Some(input) if input == "-" => InputSource::Stdin,
+            Some(input)
+                if input.contains(['/', '\\']) || Path::new(&input).extension().is_some() =>
+            {
+                InputSource::Path(input.into())
+            }
+            Some(name) => InputSource::Named(name),
+        }
+    }
+
+    pub fn read(&self, id: PuzzleId) -> Result<String, InputError> {
+        let path = match self {
+            InputSource::Stdin => {
+                return io::read_to_string(io::stdin()).map_err(|error| InputError::Io {
+                    path: "stdin".into(),
+                    error,
+                })
+            }
+            InputSource::Default => id.data_file("inputs"),
+            InputSource::Named(name) => {
+                format!("{}/inputs/{}/{name}.txt", id.year.data_dir(), id.day)
+            }
+            InputSource::Path(path) => path.to_string_lossy().into_owned(),
+            InputSource::Example(None) => id.data_file("examples"),
+            InputSource::Example(Some(part)) => {
+                format!("{}/examples/{}-{part}.txt", id.year.data_dir(), id.day)
+            }
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(input) if input.trim().is_empty() && *self == InputSource::Default => {
+                Err(InputError::NotDownloaded {
+                    id,
+                    path,
+                    empty: true,
+                })
+            }
+            Ok(input) => Ok(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound && *self == InputSource::Default => {
+                Err(InputError::NotDownloaded {
+                    id,
+                    path,
+                    empty: false,
+                })
+            }
+            Err(error) => Err(InputError::Io { path, error }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The puzzle input is missing or empty, e.g. because it was not downloaded yet.
+    NotDownloaded {
+        id: PuzzleId,
+        path: String,
+        empty: bool,
+    },
+    Io {
+        path: String,
+        error: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotDownloaded { id, path, empty } => {
+                let year = if Year::from_env() == Some(id.year) {
+                    String::new()
+                } else {
+                    format!(" --year {}", id.year)
+                };
+                write!(
+                    f,
+                    "Input file {path} {}, run `cargo download {}{year}` to fetch your puzzle input.",
+                    if *empty { "is empty" } else { "does not exist" },
+                    id.day
+                )
+            }
+            InputError::Io { path, error } => write!(f, "Could not read input {path}: {error}"),
+        }
+    }
+}
+
+/// Input selected with `--example`, `--example-part` or `--input`.
+pub fn input_source() -> InputSource {
+    InputSource::from_args(&env::args().collect::<Vec<String>>())
+}
+
+/// Part selected with `--part`, `None` if both parts should run.
+pub fn selected_part() -> Option<u8> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--part")? + 1;
+    args.get(index)?.parse().ok()
+}
+
+/// Read the input selected on the command line, see [`InputSource`].
+/// Exits if the input cannot be read or fails the checks of [`validation`].
+pub fn read_input(id: PuzzleId) -> String {
+    let input = input_source().read(id).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    if !validation::report(&input, id) {
+        process::exit(1);
+    }
+
+    input
+}
+
+/// Compare an answer with the known answer. If `--record` was passed, the answer is stored as the known answer instead.
+/// Answers of examples and inputs read from a path or stdin are neither checked nor recorded.
+fn check_answer(answer: &str, id: PuzzleId, part: u8, source: &InputSource) -> Verification {
+    let input = match source {
+        InputSource::Default => None,
+        InputSource::Named(name) => Some(name.as_str()),
+        _ => return Verification::Unknown,
+    };
+
+    let mut answers = Answers::read_from_file(id.year);
+
+    if !env::args().any(|x| x == "--record") {
+        return answers.check(id.day, part, input, answer);
+    }
+
+    answers.set(id.day, part, input, answer);
+    if let Err(e) = answers.store_file(id.year) {
+        eprintln!("Failed to store answer: {e}");
+        return Verification::Unknown;
+    }
+    Verification::Correct
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured.
+///  3. the answer has not been rejected before, is within the bounds of previous "too high" / "too low" answers
+///     and the website is not rate-limiting us.
+///
+/// Multi-line answers are submitted as the letters they draw and refused if those cannot be recognized.
+/// The response is recorded: accepted answers become known answers, rejected ones are refused locally in the future.
+fn submit_result<T: Answer>(result: T, id: PuzzleId, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,14 +476,220 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    match input_source() {
+        InputSource::Default => {}
+        InputSource::Named(name) => {
+            eprintln!("Not submitting: answers for input \"{name}\" belong to another account.");
+            process::exit(1);
+        }
+        InputSource::Example(_) => {
+            eprintln!("Not submitting: the answer was computed from an example.");
+            process::exit(1);
+        }
+        InputSource::Path(_) | InputSource::Stdin => {
+            eprintln!("Not submitting: only answers for downloaded inputs are submitted.");
+            process::exit(1);
+        }
+    }
+
+    let answer = result.submission();
+    if is_multi_line(&answer) {
+        eprintln!(
+            "Not submitting: the answer could not be decoded to letters, submit it manually."
+        );
+        process::exit(1);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let mut submissions = Submissions::read_from_file(id.year);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    if let Err(refusal) = submissions.check(id.day, part, &answer, now) {
+        eprintln!("Not submitting {answer}: {refusal}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    if let Err(e) = aoc_cli::check() {
+        eprintln!("Cannot submit: {e}");
+        process::exit(1);
+    }
+
+    println!("Submitting result...");
+
+    let response = match aoc_cli::submit(id, part, &answer) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            process::exit(1);
+        }
+    };
+
+    let submission = Submission::from_response(id.day, part, &answer, &response);
+
+    if submission.outcome == SubmissionOutcome::Correct {
+        let mut answers = Answers::read_from_file(id.year);
+        answers.set(id.day, part, None, &answer);
+        if let Err(e) = answers.store_file(id.year) {
+            eprintln!("Failed to store answer: {e}");
+        }
+    }
+
+    println!("Submission outcome: {}", submission.outcome);
+    submissions.data.push(submission);
+
+    if let Err(e) = submissions.store_file(id.year) {
+        eprintln!("Failed to store submission: {e}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bench, bench_timeout, BenchStats, InputError, InputSource};
+    use crate::{
+        day,
+        template::{PuzzleId, Year},
+    };
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_single_sample() {
+        let stats = BenchStats::from_samples(nanos(&[100]));
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_stats_for_samples() {
+        let stats = BenchStats::from_samples(nanos(&[40, 10, 30, 20]));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(40));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.samples, 4);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(nanos(&[100, 101, 99, 100, 102, 98, 100, 5000]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.max, Duration::from_nanos(102));
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn keeps_identical_samples() {
+        let stats = BenchStats::from_samples(nanos(&[50, 50, 50, 50, 50]));
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn benches_within_bench_timeout() {
+        let timeout = Duration::from_millis(20);
+        let timer = Instant::now();
+        let stats = bench(|_| thread::sleep(timeout), (), &timeout);
+
+        assert!(stats.samples + stats.outliers as u128 >= 10);
+        assert!(timer.elapsed() < bench_timeout(timeout));
+        assert_eq!(
+            bench_timeout(Duration::from_secs(5)),
+            Duration::from_secs(62)
+        );
+    }
+
+    #[test]
+    fn rejects_spikes_of_coarse_timer() {
+        let stats = BenchStats::from_samples(nanos(&[50, 50, 50, 50, 900]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(50));
+
+        let mut samples = vec![100; 16];
+        samples.extend([200, 300, 5000, 6000]);
+        let stats = BenchStats::from_samples(nanos(&samples));
+        assert_eq!(stats.outliers, 2);
+        assert_eq!(stats.max, Duration::from_nanos(300));
+    }
+
+    #[test]
+    fn rejects_few_samples_of_skewed_runs() {
+        // most samples are close together, with a long tail of slower samples, e.g. due to cache misses.
+        let mut samples: Vec<u64> = (0..24).map(|i| 1000 + i % 10).collect();
+        samples.extend((0..16).map(|i| 1010 + 4 * i * i));
+        samples.push(900);
+
+        let stats = BenchStats::from_samples(nanos(&samples));
+        assert_eq!(stats.outliers, 5);
+        assert_eq!(stats.min, Duration::from_nanos(900));
+        assert_eq!(stats.max, Duration::from_nanos(1410));
+    }
+
+    fn source(args: &str) -> InputSource {
+        let args: Vec<String> = args.split_whitespace().map(Into::into).collect();
+        InputSource::from_args(&args)
+    }
+
+    #[test]
+    fn selects_input_source() {
+        assert_eq!(source("2024-01"), InputSource::Default);
+        assert_eq!(
+            source("2024-01 --input alice"),
+            InputSource::Named("alice".into())
+        );
+        assert_eq!(source("2024-01 --example"), InputSource::Example(None));
+        assert_eq!(
+            source("2024-01 --example-part 2"),
+            InputSource::Example(Some(2))
+        );
+        assert_eq!(
+            source("2024-01 --input alice --example"),
+            InputSource::Example(None)
+        );
+    }
+
+    #[test]
+    fn reads_paths_and_stdin() {
+        assert_eq!(source("2024-01 --input -"), InputSource::Stdin);
+        assert_eq!(
+            source("2024-01 --input /tmp/input"),
+            InputSource::Path("/tmp/input".into())
+        );
+        assert_eq!(
+            source("2024-01 --input input.txt"),
+            InputSource::Path("input.txt".into())
+        );
+    }
+
+    #[test]
+    fn suggests_download_for_missing_input() {
+        let id = PuzzleId::new(Year::new(2015).unwrap(), day!(3));
+        let error = InputSource::Default.read(id).unwrap_err();
+
+        assert!(matches!(
+            error,
+            InputError::NotDownloaded { empty: false, .. }
+        ));
+        assert!(error
+            .to_string()
+            .contains("run `cargo download 03 --year 2015`"));
+    }
 }
@@ -0,0 +1,206 @@
+/// Selection of days shared by `solve`, `all` and `time`.
+///
+/// Days are given as a comma-separated list of days and ranges, e.g. `1-5,8,12-`. Open ranges extend to the first
+/// or last day of advent. The selection can be narrowed further with a [`DayFilter`].
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::answers::Answers;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, Year};
+
+/// A set of days picked on the command line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaySelection(HashSet<Day>);
+
+impl DaySelection {
+    /// Selects every day of advent.
+    pub fn all() -> Self {
+        Self(all_days().collect())
+    }
+
+    pub fn days(&self) -> &HashSet<Day> {
+        &self.0
+    }
+
+    /// The selected days in ascending order.
+    pub fn sorted(&self) -> Vec<Day> {
+        all_days().filter(|day| self.0.contains(day)).collect()
+    }
+}
+
+impl From<HashSet<Day>> for DaySelection {
+    fn from(days: HashSet<Day>) -> Self {
+        Self(days)
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = HashSet::new();
+
+        for item in s.split(',').map(str::trim) {
+            let error = || DaySelectionError(item.to_string());
+            let parse = |x: &str, default: u8| -> Result<Day, DaySelectionError> {
+                if x.is_empty() {
+                    return Ok(Day::new(default).unwrap());
+                }
+                x.trim().parse().map_err(|_| error())
+            };
+
+            let (first, last) = match item.split_once('-') {
+                Some((first, last)) => (parse(first, 1)?, parse(last, 25)?),
+                None if !item.is_empty() => {
+                    let day = parse(item, 0)?;
+                    (day, day)
+                }
+                None => return Err(error()),
+            };
+
+            if first > last {
+                return Err(error());
+            }
+
+            days.extend(all_days().filter(|day| *day >= first && *day <= last));
+        }
+
+        Ok(Self(days))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`].
+#[derive(Debug)]
+pub struct DaySelectionError(String);
+
+impl Error for DaySelectionError {}
+
+impl Display for DaySelectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day selection `{}`, expecting days or ranges between 1 and 25, e.g. `1-5,8,12-`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Narrows a [`DaySelection`] down based on stored answers and timings.
+#[derive(Clone, Debug, Default)]
+pub struct DayFilter {
+    /// Only keep days that are missing a known answer.
+    pub only_unsolved: bool,
+    /// Only keep days whose stored timing took at least this many milliseconds.
+    pub only_slow: Option<f64>,
+}
+
+impl DayFilter {
+    pub fn apply(&self, year: Year, selection: &DaySelection) -> DaySelection {
+        let answers = self.only_unsolved.then(|| Answers::read_from_file(year));
+        let timings = self.only_slow.map(|_| Timings::read_from_file(year));
+
+        selection
+            .days()
+            .iter()
+            .copied()
+            .filter(|day| answers.as_ref().is_none_or(|a| !is_solved(a, *day)))
+            .filter(|day| {
+                timings
+                    .as_ref()
+                    .zip(self.only_slow)
+                    .is_none_or(|(t, millis)| is_slow(t, *day, millis))
+            })
+            .collect::<HashSet<Day>>()
+            .into()
+    }
+}
+
+/// A day is solved once every part has a known answer. Day 25 only has one part.
+fn is_solved(answers: &Answers, day: Day) -> bool {
+    let parts: &[u8] = if day == 25 { &[1] } else { &[1, 2] };
+    parts
+        .iter()
+        .all(|part| answers.get(day, *part, None).is_some())
+}
+
+fn is_slow(timings: &Timings, day: Day, millis: f64) -> bool {
+    timings
+        .data
+        .iter()
+        .any(|t| t.day == day && t.total_nanos >= millis * 1_000_000_f64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_slow, is_solved, DaySelection};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            timings::{Timing, Timings},
+        },
+    };
+
+    #[test]
+    fn parses_selections() {
+        let selection: DaySelection = "1-3,8,23-".parse().unwrap();
+        assert_eq!(
+            selection.sorted(),
+            [
+                day!(1),
+                day!(2),
+                day!(3),
+                day!(8),
+                day!(23),
+                day!(24),
+                day!(25)
+            ]
+        );
+
+        let selection: DaySelection = "-2, 5".parse().unwrap();
+        assert_eq!(selection.sorted(), [day!(1), day!(2), day!(5)]);
+
+        let selection: DaySelection = "7".parse().unwrap();
+        assert_eq!(selection.sorted(), [day!(7)]);
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        for s in ["", "0", "26", "5-3", "1-30", "a", "1,,2"] {
+            assert!(s.parse::<DaySelection>().is_err(), "accepted `{s}`");
+        }
+    }
+
+    #[test]
+    fn filters_unsolved_and_slow_days() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, None, "1");
+        answers.set(day!(1), 2, None, "2");
+        answers.set(day!(2), 1, None, "1");
+        answers.set(day!(25), 1, None, "1");
+
+        assert!(is_solved(&answers, day!(1)));
+        assert!(!is_solved(&answers, day!(2)));
+        assert!(is_solved(&answers, day!(25)));
+
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: None,
+                part_2: None,
+                total_nanos: 2e+7,
+            }],
+        };
+
+        assert!(is_slow(&timings, day!(1), 20.0));
+        assert!(!is_slow(&timings, day!(1), 25.0));
+        assert!(!is_slow(&timings, day!(2), 0.0));
+    }
+}
@@ -0,0 +1,573 @@
+/// Log of answers submitted to adventofcode.com and the responses received.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+static SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// Every year keeps its own submissions file, e.g. `data/2024/submissions.json`.
+fn get_file_path(year: Year) -> String {
+    format!("{}/{SUBMISSIONS_FILE_NAME}", year.data_dir())
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong answer without a hint.
+    Incorrect,
+    /// The answer was not checked because the previous submission was too recent.
+    RateLimited,
+    /// The part has already been solved.
+    AlreadySolved,
+    /// The response could not be understood.
+    Unknown,
+}
+
+impl SubmissionOutcome {
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow | SubmissionOutcome::Incorrect
+        )
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::TooHigh => "too_high",
+            SubmissionOutcome::TooLow => "too_low",
+            SubmissionOutcome::Incorrect => "incorrect",
+            SubmissionOutcome::RateLimited => "rate_limited",
+            SubmissionOutcome::AlreadySolved => "already_solved",
+            SubmissionOutcome::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for SubmissionOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            SubmissionOutcome::Correct,
+            SubmissionOutcome::TooHigh,
+            SubmissionOutcome::TooLow,
+            SubmissionOutcome::Incorrect,
+            SubmissionOutcome::RateLimited,
+            SubmissionOutcome::AlreadySolved,
+            SubmissionOutcome::Unknown,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.as_str() == s)
+        .ok_or_else(|| format!("unknown submission outcome `{s}`."))
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::TooHigh => "too high",
+            SubmissionOutcome::TooLow => "too low",
+            SubmissionOutcome::Incorrect => "incorrect",
+            SubmissionOutcome::RateLimited => "rate-limited",
+            SubmissionOutcome::AlreadySolved => "already solved",
+            SubmissionOutcome::Unknown => "unknown",
+        })
+    }
+}
+
+/// Represents a single submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Seconds the website asked to wait before the next submission, if any.
+    pub wait_secs: Option<u64>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    /// Creates a submission from the text of the response page.
+    pub fn from_response(day: Day, part: u8, answer: &str, response: &str) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.into(),
+            outcome: parse_outcome(response),
+            wait_secs: parse_wait_secs(response),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        }
+    }
+}
+
+/// Represents all submissions made so far.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns no submissions.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_file_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the submission that rejected `answer` before, if any.
+    pub fn find_wrong(&self, day: Day, part: u8, answer: &str) -> Option<&Submission> {
+        self.data
+            .iter()
+            .find(|s| s.day == day && s.part == part && s.answer == answer && s.outcome.is_wrong())
+    }
+}
+
+/// Reason for refusing to submit an answer locally.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Refusal {
+    /// The exact answer has been rejected before.
+    KnownWrong(SubmissionOutcome),
+    /// The answer is not below an answer that was reported as too high.
+    NotBelow(i128),
+    /// The answer is not above an answer that was reported as too low.
+    NotAbove(i128),
+    /// The website asked to wait before submitting again.
+    Cooldown { remaining_secs: u64 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "this answer was already rejected as {outcome}.")
+            }
+            Refusal::NotBelow(bound) => {
+                write!(f, "{bound} was too high, so the answer must be lower.")
+            }
+            Refusal::NotAbove(bound) => {
+                write!(f, "{bound} was too low, so the answer must be higher.")
+            }
+            Refusal::Cooldown { remaining_secs } => write!(
+                f,
+                "the previous attempt was rate-limited, {}m {}s left to wait.",
+                remaining_secs / 60,
+                remaining_secs % 60
+            ),
+        }
+    }
+}
+
+impl Submissions {
+    /// The exclusive (lower, upper) bounds for the answer, derived from answers that were reported as too low or too high.
+    pub fn bounds(&self, day: Day, part: u8) -> (Option<i128>, Option<i128>) {
+        let numeric = |outcome: SubmissionOutcome| {
+            self.data
+                .iter()
+                .filter(move |s| s.day == day && s.part == part && s.outcome == outcome)
+                .filter_map(|s| s.answer.trim().parse::<i128>().ok())
+        };
+
+        (
+            numeric(SubmissionOutcome::TooLow).max(),
+            numeric(SubmissionOutcome::TooHigh).min(),
+        )
+    }
+
+    /// Seconds left until the website accepts another submission, based on the most recent submission.
+    pub fn remaining_cooldown(&self, now: u64) -> Option<u64> {
+        let latest = self.data.iter().max_by_key(|s| s.timestamp)?;
+        let remaining = (latest.timestamp + latest.wait_secs?).saturating_sub(now);
+        (remaining > 0).then_some(remaining)
+    }
+
+    /// Check whether `answer` may be submitted at time `now` (seconds since the unix epoch).
+    pub fn check(&self, day: Day, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(previous) = self.find_wrong(day, part, answer) {
+            return Err(Refusal::KnownWrong(previous.outcome));
+        }
+
+        if let Ok(value) = answer.trim().parse::<i128>() {
+            let (lower, upper) = self.bounds(day, part);
+
+            if let Some(upper) = upper.filter(|upper| value >= *upper) {
+                return Err(Refusal::NotBelow(upper));
+            }
+
+            if let Some(lower) = lower.filter(|lower| value <= *lower) {
+                return Err(Refusal::NotAbove(lower));
+            }
+        }
+
+        match self.remaining_cooldown(now) {
+            Some(remaining_secs) => Err(Refusal::Cooldown { remaining_secs }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Determine the verdict from the text of the response page.
+pub fn parse_outcome(response: &str) -> SubmissionOutcome {
+    let response = response.to_lowercase();
+
+    if response.contains("that's the right answer") {
+        SubmissionOutcome::Correct
+    } else if response.contains("your answer is too high") {
+        SubmissionOutcome::TooHigh
+    } else if response.contains("your answer is too low") {
+        SubmissionOutcome::TooLow
+    } else if response.contains("that's not the right answer") {
+        SubmissionOutcome::Incorrect
+    } else if response.contains("you gave an answer too recently") {
+        SubmissionOutcome::RateLimited
+    } else if response.contains("you don't seem to be solving the right level") {
+        SubmissionOutcome::AlreadySolved
+    } else {
+        SubmissionOutcome::Unknown
+    }
+}
+
+/// Parse the cooldown from phrases like "You have 1m 43s left to wait" or "Please wait 5 minutes".
+pub fn parse_wait_secs(response: &str) -> Option<u64> {
+    let response = response.to_lowercase();
+
+    if let Some(pos) = response.find(" left to wait") {
+        let start = response[..pos].rfind("you have ")? + "you have ".len();
+        return response[start..pos]
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    let start = response.find("please wait ")? + "please wait ".len();
+    let mut tokens = response[start..].split_whitespace();
+    let value = match tokens.next()? {
+        "one" => 1,
+        x => x.parse().ok()?,
+    };
+
+    match tokens.next()? {
+        unit if unit.starts_with("minute") => Some(value * 60),
+        unit if unit.starts_with("second") => Some(value),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.as_str().into()),
+        );
+        map.insert(
+            "wait_secs".into(),
+            match value.wait_secs {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .filter(|x| *x == 1 || *x == 2)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.outcome to be a string.")?
+            .parse()?;
+
+        let wait_secs = json
+            .get("wait_secs")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64);
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+            wait_secs,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        parse_outcome, parse_wait_secs, Refusal, Submission, SubmissionOutcome, Submissions,
+    };
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            parse_outcome("That's the right answer! You are one gold star closer."),
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            parse_outcome("That's not the right answer; your answer is too high."),
+            SubmissionOutcome::TooHigh
+        );
+        assert_eq!(
+            parse_outcome("That's not the right answer; your answer is too low."),
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(
+            parse_outcome("That's not the right answer. If you're stuck, ..."),
+            SubmissionOutcome::Incorrect
+        );
+        assert_eq!(
+            parse_outcome("You gave an answer too recently; you have to wait after submitting an answer before trying again."),
+            SubmissionOutcome::RateLimited
+        );
+        assert_eq!(
+            parse_outcome(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            SubmissionOutcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_outcome("500 Internal Server Error"),
+            SubmissionOutcome::Unknown
+        );
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            parse_wait_secs("You gave an answer too recently. You have 1m 43s left to wait."),
+            Some(103)
+        );
+        assert_eq!(parse_wait_secs("You have 34s left to wait."), Some(34));
+        assert_eq!(
+            parse_wait_secs("your answer is too low. Please wait one minute before trying again."),
+            Some(60)
+        );
+        assert_eq!(
+            parse_wait_secs("Please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(parse_wait_secs("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn finds_wrong_answers() {
+        let submissions = Submissions {
+            data: vec![
+                Submission::from_response(day!(1), 1, "10", "your answer is too high."),
+                Submission::from_response(day!(1), 1, "5", "That's the right answer!"),
+            ],
+        };
+
+        assert!(submissions.find_wrong(day!(1), 1, "10").is_some());
+        assert!(submissions.find_wrong(day!(1), 2, "10").is_none());
+        assert!(submissions.find_wrong(day!(1), 1, "5").is_none());
+    }
+
+    fn submission(answer: &str, outcome: SubmissionOutcome, timestamp: u64) -> Submission {
+        Submission {
+            day: day!(1),
+            part: 1,
+            answer: answer.into(),
+            outcome,
+            wait_secs: Some(60),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn derives_bounds() {
+        let submissions = Submissions {
+            data: vec![
+                submission("100", SubmissionOutcome::TooHigh, 0),
+                submission("80", SubmissionOutcome::TooHigh, 0),
+                submission("10", SubmissionOutcome::TooLow, 0),
+                submission("abc", SubmissionOutcome::TooLow, 0),
+            ],
+        };
+
+        assert_eq!(submissions.bounds(day!(1), 1), (Some(10), Some(80)));
+        assert_eq!(submissions.bounds(day!(1), 2), (None, None));
+    }
+
+    #[test]
+    fn refuses_out_of_range_answers() {
+        let submissions = Submissions {
+            data: vec![
+                submission("80", SubmissionOutcome::TooHigh, 0),
+                submission("10", SubmissionOutcome::TooLow, 0),
+            ],
+        };
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "80", 1000),
+            Err(Refusal::KnownWrong(SubmissionOutcome::TooHigh))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "95", 1000),
+            Err(Refusal::NotBelow(80))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "3", 1000),
+            Err(Refusal::NotAbove(10))
+        );
+        assert_eq!(submissions.check(day!(1), 1, "42", 1000), Ok(()));
+        assert_eq!(submissions.check(day!(1), 2, "95", 1000), Ok(()));
+    }
+
+    #[test]
+    fn reports_remaining_cooldown() {
+        let submissions = Submissions {
+            data: vec![
+                submission("80", SubmissionOutcome::TooHigh, 0),
+                submission("40", SubmissionOutcome::TooHigh, 100),
+            ],
+        };
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "20", 130),
+            Err(Refusal::Cooldown { remaining_secs: 30 })
+        );
+        assert_eq!(submissions.check(day!(1), 1, "20", 160), Ok(()));
+        assert_eq!(
+            Refusal::Cooldown { remaining_secs: 90 }.to_string(),
+            "the previous attempt was rate-limited, 1m 30s left to wait."
+        );
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = Submissions {
+            data: vec![Submission::from_response(
+                day!(3),
+                2,
+                "42",
+                "your answer is too low. Please wait one minute before trying again.",
+            )],
+        };
+
+        let json = JsonValue::from(submissions.clone()).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap().data, submissions.data);
+    }
+}
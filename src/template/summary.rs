@@ -0,0 +1,280 @@
+/// Summarizes the outcome of running many days, see `cargo all`.
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::template::answers::Mismatch;
+use crate::template::results::{PartResult, PartStatus};
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Overall outcome of a single day, ordered from best to worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayStatus {
+    Ok,
+    /// A part returned `None`.
+    Unsolved,
+    /// A part returned an answer different from the known answer.
+    Changed,
+    /// The input of the day is missing, so no part ran.
+    MissingInput,
+    /// The input of the day has a fatal issue, so no part ran.
+    InvalidInput,
+    Panicked,
+    TimedOut,
+    Crashed,
+}
+
+impl DayStatus {
+    fn of_part(result: &PartResult, mismatches: &[Mismatch]) -> Self {
+        match result.status {
+            PartStatus::Solved
+                if mismatches
+                    .iter()
+                    .any(|m| m.day == result.day && m.part == result.part) =>
+            {
+                DayStatus::Changed
+            }
+            PartStatus::Solved => DayStatus::Ok,
+            PartStatus::Unsolved => DayStatus::Unsolved,
+            PartStatus::Panicked => DayStatus::Panicked,
+            PartStatus::TimedOut => DayStatus::TimedOut,
+            PartStatus::Running | PartStatus::Crashed => DayStatus::Crashed,
+            PartStatus::MissingInput => DayStatus::MissingInput,
+            PartStatus::InvalidInput => DayStatus::InvalidInput,
+        }
+    }
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            DayStatus::Ok => "✔ ok",
+            DayStatus::Unsolved => "✖ unsolved",
+            DayStatus::Changed => "✖ answer changed",
+            DayStatus::MissingInput => "✖ missing input",
+            DayStatus::InvalidInput => "✖ invalid input",
+            DayStatus::Panicked => "✖ panicked",
+            DayStatus::TimedOut => "✖ timed out",
+            DayStatus::Crashed => "✖ crashed",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Outcome of a single day that was run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaySummary {
+    pub day: Day,
+    /// Results of part one and part two, `None` if the part did not run.
+    pub parts: [Option<PartResult>; 2],
+    pub status: DayStatus,
+}
+
+impl DaySummary {
+    fn total(&self) -> Duration {
+        let nanos: f64 = self.parts.iter().flatten().map(|r| r.nanos).sum();
+        Duration::from_nanos(nanos as u64)
+    }
+}
+
+/// Summarize the results of every day that reported at least one part, in day order.
+pub fn summarize(results: &[PartResult], mismatches: &[Mismatch]) -> Vec<DaySummary> {
+    all_days()
+        .filter_map(|day| {
+            let part = |part: u8| {
+                results
+                    .iter()
+                    .find(|r| r.day == day && r.part == part)
+                    .cloned()
+            };
+            let parts = [part(1), part(2)];
+
+            let status = parts
+                .iter()
+                .flatten()
+                .map(|r| DayStatus::of_part(r, mismatches))
+                .max()?;
+
+            Some(DaySummary { day, parts, status })
+        })
+        .collect()
+}
+
+/// Exit code for a set of summaries: `0` if every day is ok, `1` otherwise.
+pub fn exit_code(summaries: &[DaySummary]) -> i32 {
+    i32::from(summaries.iter().any(|s| s.status != DayStatus::Ok))
+}
+
+/// Print a table of all days, styled like the output of the individual parts.
+pub fn print(summaries: &[DaySummary]) {
+    if summaries.is_empty() {
+        return;
+    }
+
+    let answer_width = summaries
+        .iter()
+        .flat_map(|s| s.parts.iter().flatten())
+        .map(|r| format_answer(r).chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Part 1".len());
+
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!(
+        "{ANSI_BOLD}Day  {:answer_width$}  {:answer_width$}  {:>10}  Status{ANSI_RESET}",
+        "Part 1", "Part 2", "Time"
+    );
+
+    for summary in summaries {
+        let parts = summary.parts.iter().map(|part| {
+            let answer = part.as_ref().map_or("-".into(), format_answer);
+            let padding = " ".repeat(answer_width - answer.chars().count());
+            match part {
+                Some(r) if r.answer.is_some() => {
+                    format!("{ANSI_BOLD}{answer}{ANSI_RESET}{padding}")
+                }
+                _ => format!("{answer}{padding}"),
+            }
+        });
+        let parts: Vec<String> = parts.collect();
+
+        println!(
+            "{}   {}  {}  {ANSI_ITALIC}{:>10}{ANSI_RESET}  {}",
+            summary.day,
+            parts[0],
+            parts[1],
+            format!("{:.1?}", summary.total()),
+            summary.status
+        );
+    }
+
+    let failed = summaries
+        .iter()
+        .filter(|s| s.status != DayStatus::Ok)
+        .count();
+
+    if failed > 0 {
+        println!("\n{failed} of {} day(s) failed.", summaries.len());
+    }
+}
+
+/// Answers are printed like in `run_part`, multi-line answers are only hinted at.
+fn format_answer(result: &PartResult) -> String {
+    match &result.answer {
+        Some(answer) if answer.contains('\n') => "▼".into(),
+        Some(answer) => answer.clone(),
+        None => "✖".into(),
+    }
+}
+
+/// Lists every part that panicked, timed out or crashed.
+pub fn print_failures(results: &[PartResult]) {
+    let failures: Vec<&PartResult> = results
+        .iter()
+        .filter(|r| {
+            matches!(
+                r.status,
+                PartStatus::Panicked | PartStatus::TimedOut | PartStatus::Crashed
+            )
+        })
+        .collect();
+
+    if failures.is_empty() {
+        return;
+    }
+
+    println!("\n{ANSI_BOLD}Failed:{ANSI_RESET}");
+    for r in failures {
+        println!("Day {} · Part {}: ✖ {}", r.day, r.part, r.status);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{exit_code, summarize, DayStatus};
+    use crate::{
+        day,
+        template::{
+            answers::Mismatch,
+            results::{PartResult, PartStatus},
+        },
+    };
+
+    #[test]
+    fn summarizes_days_with_results() {
+        let results = [
+            PartResult::answered(day!(1), 1, Some("42"), 1_000.0),
+            PartResult::answered(day!(1), 2, Some("42"), 1_000.0),
+            PartResult::answered(day!(3), 1, Some("42"), 1_000.0),
+            PartResult::placeholder(day!(3), 2, PartStatus::TimedOut),
+            PartResult::answered(day!(25), 1, Some("42"), 1_000.0),
+        ];
+
+        let summaries = summarize(&results, &[]);
+
+        assert_eq!(summaries.len(), 3);
+        assert_eq!(summaries[0].status, DayStatus::Ok);
+        assert_eq!(summaries[1].day, day!(3));
+        assert_eq!(summaries[1].status, DayStatus::TimedOut);
+        assert_eq!(summaries[2].status, DayStatus::Ok);
+        assert_eq!(summaries[2].parts[1], None);
+        assert_eq!(exit_code(&summaries), 1);
+        assert_eq!(exit_code(&summaries[..1]), 0);
+    }
+
+    #[test]
+    fn picks_worst_status_of_a_day() {
+        let results = [
+            PartResult::placeholder(day!(2), 1, PartStatus::Unsolved),
+            PartResult::placeholder(day!(2), 2, PartStatus::Panicked),
+            PartResult::answered(day!(4), 1, Some("42"), 1_000.0),
+            PartResult::placeholder(day!(4), 2, PartStatus::Running),
+            PartResult::placeholder(day!(5), 1, PartStatus::Unsolved),
+        ];
+
+        let summaries = summarize(&results, &[]);
+
+        assert_eq!(summaries[0].status, DayStatus::Panicked);
+        assert_eq!(summaries[1].status, DayStatus::Crashed);
+        assert_eq!(summaries[2].status, DayStatus::Unsolved);
+    }
+
+    #[test]
+    fn fails_days_with_input_issues() {
+        let results = [
+            PartResult::answered(day!(1), 1, Some("42"), 1_000.0),
+            PartResult::placeholder(day!(2), 1, PartStatus::MissingInput),
+            PartResult::placeholder(day!(2), 2, PartStatus::MissingInput),
+            PartResult::placeholder(day!(3), 1, PartStatus::InvalidInput),
+        ];
+
+        let summaries = summarize(&results, &[]);
+
+        assert_eq!(summaries.len(), 3);
+        assert_eq!(summaries[1].status, DayStatus::MissingInput);
+        assert_eq!(summaries[1].status.to_string(), "✖ missing input");
+        assert_eq!(summaries[2].status, DayStatus::InvalidInput);
+        assert_eq!(exit_code(&summaries[1..2]), 1);
+        assert_eq!(exit_code(&summaries[2..]), 1);
+    }
+
+    #[test]
+    fn reports_changed_answers() {
+        let results = [
+            PartResult::answered(day!(1), 1, Some("42"), 1_000.0),
+            PartResult::answered(day!(1), 2, Some("42"), 1_000.0),
+        ];
+        let mismatches = [Mismatch {
+            day: day!(1),
+            part: 2,
+            expected: "41".into(),
+            actual: Some("42".into()),
+        }];
+
+        let summaries = summarize(&results, &mismatches);
+
+        assert_eq!(summaries[0].status, DayStatus::Changed);
+        assert_eq!(exit_code(&summaries), 1);
+    }
+}
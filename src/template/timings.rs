@@ -1,19 +1,38 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
+
+/// Every year keeps its own timings file, e.g. `data/2024/timings.json`.
+fn get_file_path(year: Year) -> String {
+    format!("{}/{TIMINGS_FILE_NAME}", year.data_dir())
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+/// Represents benchmark times for a single part.
+///
+/// # Display
+/// This value displays as the formatted average duration, e.g. `74.1µs`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Average duration in nanoseconds.
+    pub nanos: f64,
+    /// Number of samples taken. Timings read from files that predate this field report `0`.
+    pub samples: u128,
+    pub median_nanos: Option<f64>,
+    pub p95_nanos: Option<f64>,
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -23,15 +42,16 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_file_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_file_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -55,6 +75,35 @@ impl Timings {
         Timings { data }
     }
 
+    /// Fill in the part that was not run from `stored`, for timings where only `part` was run.
+    pub fn keep_other_part(&self, stored: &Self, part: u8) -> Self {
+        let data = self
+            .data
+            .iter()
+            .map(|timing| {
+                let mut timing = timing.clone();
+                let stored = stored.data.iter().find(|t| t.day == timing.day);
+
+                if let Some(stored) = stored {
+                    if part == 1 {
+                        timing.part_2.clone_from(&stored.part_2);
+                    } else {
+                        timing.part_1.clone_from(&stored.part_1);
+                    }
+                }
+
+                timing.total_nanos = [&timing.part_1, &timing.part_2]
+                    .into_iter()
+                    .flatten()
+                    .map(|t| t.nanos)
+                    .sum();
+                timing
+            })
+            .collect();
+
+        Timings { data }
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
@@ -65,6 +114,121 @@ impl Timings {
             .iter()
             .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
+
+    /// Compare every part in `self` against the same part in `baseline`.
+    /// Parts that are missing on either side are skipped.
+    pub fn compare(&self, baseline: &Self) -> Vec<PartDelta> {
+        self.data
+            .iter()
+            .flat_map(|timing| {
+                let previous = baseline.data.iter().find(|t| t.day == timing.day);
+                [1, 2].into_iter().filter_map(move |part| {
+                    Some(PartDelta {
+                        day: timing.day,
+                        part,
+                        previous_nanos: previous?.part_nanos(part)?,
+                        current_nanos: timing.part_nanos(part)?,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+impl Timing {
+    /// Timing of a part, if the part has been benched.
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Execution time of a part in nanoseconds, if the part has been benched.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part).map(|t| t.nanos)
+    }
+}
+
+impl PartTiming {
+    /// Creates a timing from its average duration only.
+    pub fn from_nanos(nanos: f64, samples: u128) -> Self {
+        Self {
+            nanos,
+            samples,
+            median_nanos: None,
+            p95_nanos: None,
+        }
+    }
+}
+
+impl Display for PartTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1?}", Duration::from_secs_f64(self.nanos / 1e9))
+    }
+}
+
+/// Parses a duration formatted with `{:?}` back into nanoseconds.
+/// Used to read timings that were stored as pre-formatted strings.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse_to_float = |postfix: &str| s.split(postfix).next()?.trim().parse::<f64>().ok();
+
+    match s {
+        s if s.contains("ns") => parse_to_float("ns"),
+        s if s.contains("µs") => parse_to_float("µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float("ms").map(|x| x * 1_000_000_f64),
+        _ => parse_to_float("s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Change in execution time of a single part between two runs.
+#[derive(Clone, Debug)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub previous_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl PartDelta {
+    pub fn delta_nanos(&self) -> f64 {
+        self.current_nanos - self.previous_nanos
+    }
+
+    /// Relative change in percent. Positive values mean the part got slower.
+    pub fn delta_percent(&self) -> f64 {
+        if self.previous_nanos == 0.0 {
+            return 0.0;
+        }
+        self.delta_nanos() / self.previous_nanos * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.delta_percent() > threshold
+    }
+}
+
+impl Display for PartDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.delta_nanos() < 0.0 { "-" } else { "+" };
+        let format = |nanos: f64| format!("{:.1?}", Duration::from_secs_f64(nanos / 1e9));
+
+        write!(
+            f,
+            "Day {} Part {}: {} → {} ({sign}{}, {:+.2}%)",
+            self.day,
+            self.part,
+            format(self.previous_nanos),
+            format(self.current_nanos),
+            format(self.delta_nanos().abs()),
+            self.delta_percent()
+        )
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -87,7 +251,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
 
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -114,21 +285,18 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
+            match &value.part_1 {
+                Some(x) => x.into(),
                 None => JsonValue::Null,
             },
         );
 
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
+            match &value.part_2 {
+                Some(x) => x.into(),
                 None => JsonValue::Null,
             },
         );
@@ -153,13 +321,25 @@ impl TryFrom<&JsonValue> for Timing {
 
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    PartTiming::try_from(v).map(Some)
+                }
+            })
+            .ok_or("Expected timing.part_1 to be null, string or object.")??;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    PartTiming::try_from(v).map(Some)
+                }
+            })
+            .ok_or("Expected timing.part_2 to be null, string or object.")??;
 
         let total_nanos = json
             .get("total_nanos")
@@ -168,8 +348,8 @@ impl TryFrom<&JsonValue> for Timing {
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            part_1,
+            part_2,
             total_nanos,
         })
     }
@@ -177,30 +357,96 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        if let Some(median_nanos) = value.median_nanos {
+            map.insert("median_nanos".into(), JsonValue::Number(median_nanos));
+        }
+
+        if let Some(p95_nanos) = value.p95_nanos {
+            map.insert("p95_nanos".into(), JsonValue::Number(p95_nanos));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    /// Accepts both the current object format and the legacy format, which stored a formatted string like `"74.13ns"`.
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        if let Some(timing_str) = value.get::<String>() {
+            return parse_duration(timing_str)
+                .map(|nanos| PartTiming::from_nanos(nanos, 0))
+                .ok_or_else(|| format!("Could not parse legacy part timing `{timing_str}`."));
+        }
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a string or JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part timing.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u128)
+            .ok_or("Expected part timing.samples to be a number.")?;
+
+        let median_nanos = json
+            .get("median_nanos")
+            .and_then(|v| v.get::<f64>().copied());
+        let p95_nanos = json.get("p95_nanos").and_then(|v| v.get::<f64>().copied());
+
+        Ok(PartTiming {
+            nanos,
+            samples,
+            median_nanos,
+            p95_nanos,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    /// Shorthand for a part timing of `millis` milliseconds.
+    pub fn ms(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming::from_nanos(millis * 1e6, 10))
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: ms(10.0),
+                    part_2: ms(20.0),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: ms(30.0),
+                    part_2: ms(40.0),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    part_1: ms(40.0),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
@@ -213,14 +459,29 @@ mod tests {
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10, "p95_nanos": 1200000 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 10);
+            assert_eq!(part_1.median_nanos, None);
+            assert_eq!(part_1.p95_nanos, Some(1_200_000_f64));
+            assert_eq!(part_1.to_string(), "1.0ms");
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_legacy_json_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.1µs", "part_2": "1.5s", "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_nanos(1).unwrap().round(), 74_100.0);
+            assert_eq!(timing.part_nanos(2).unwrap(), 1_500_000_000.0);
+            assert_eq!(timing.part_1.as_ref().unwrap().samples, 0);
         }
 
         #[test]
@@ -250,6 +511,15 @@ mod tests {
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        #[test]
+        fn roundtrips_part_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = crate::template::timings::Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -274,18 +544,20 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
+        use super::ms;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    part_1: ms(1.0),
+                    part_2: ms(2.0),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +565,13 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    part_1: ms(1.0),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -313,7 +585,64 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use super::{get_mock_timings, ms};
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let baseline = get_mock_timings();
+            let current = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(1),
+                        part_1: ms(15.0),
+                        part_2: ms(10.0),
+                        total_nanos: 2.5e+7,
+                    },
+                    Timing {
+                        day: day!(4),
+                        part_1: ms(40.0),
+                        part_2: ms(1.0),
+                        total_nanos: 4.1e+7,
+                    },
+                ],
+            };
+
+            let deltas = current.compare(&baseline);
+            assert_eq!(deltas.len(), 3);
+            assert_eq!(deltas[0].delta_percent(), 50.0);
+            assert_eq!(deltas[0].is_regression(10.0), true);
+            assert_eq!(deltas[1].delta_percent(), -50.0);
+            assert_eq!(deltas[1].is_regression(10.0), false);
+            assert_eq!(deltas[2].day, day!(4));
+            assert_eq!(deltas[2].delta_nanos(), 0.0);
+        }
+
+        #[test]
+        fn formats_deltas() {
+            let deltas = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    part_1: ms(20.0),
+                    part_2: None,
+                    total_nanos: 2e+7,
+                }],
+            }
+            .compare(&get_mock_timings());
+
+            assert_eq!(
+                deltas[0].to_string(),
+                "Day 02 Part 1: 30.0ms → 20.0ms (-10.0ms, -33.33%)"
+            );
         }
     }
 
@@ -323,7 +652,7 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, ms};
 
         #[test]
         fn handles_disjunct_timings() {
@@ -365,6 +694,36 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_other_part_of_partial_timings() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(2),
+                        part_1: None,
+                        part_2: ms(10.0),
+                        total_nanos: 1e+7,
+                    },
+                    Timing {
+                        day: day!(3),
+                        part_1: None,
+                        part_2: ms(5.0),
+                        total_nanos: 5e+6,
+                    },
+                ],
+            };
+            let merged = timings.merge(&other.keep_other_part(&timings, 2));
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[1].part_1, ms(30.0));
+            assert_eq!(merged.data[1].part_2, ms(10.0));
+            assert_eq!(merged.data[1].total_nanos, 4e+7);
+            assert_eq!(merged.data[2].part_1, None);
+            assert_eq!(merged.data[2].total_nanos, 5e+6);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
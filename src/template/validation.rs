@@ -0,0 +1,151 @@
+/// Sanity checks on an input before it is passed to a solution.
+///
+/// A broken input usually does not crash a solution but produces a nonsense answer, e.g. an empty grid has a height
+/// of zero. These checks catch the common ways of ending up with a broken input file.
+use std::fmt::Display;
+
+use crate::template::PuzzleId;
+
+/// A problem found in an input.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputIssue {
+    Empty,
+    /// An HTML page or error message was saved instead of the input, contains the first line of it.
+    ErrorPage(String),
+    CrlfLineEndings,
+    MissingTrailingNewline,
+}
+
+impl InputIssue {
+    /// Whether the input is unusable. Other issues are reported, but the input is still solved.
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, InputIssue::Empty | InputIssue::ErrorPage(_))
+    }
+}
+
+impl Display for InputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputIssue::Empty => write!(f, "the input is empty."),
+            InputIssue::ErrorPage(line) => write!(
+                f,
+                "the input looks like an error page instead of a puzzle input: \"{line}\". \
+                Check that your session cookie is valid and download the input again."
+            ),
+            InputIssue::CrlfLineEndings => write!(
+                f,
+                "the input has Windows line endings (CRLF). \
+                Convert it to LF line endings, e.g. with `dos2unix`, or download it again."
+            ),
+            InputIssue::MissingTrailingNewline => write!(
+                f,
+                "the input does not end with a newline, it may have been copied incompletely. \
+                Download it again if the answer looks wrong."
+            ),
+        }
+    }
+}
+
+/// Responses of adventofcode.com that end up in an input file when downloading fails.
+const ERROR_MESSAGES: [&str; 3] = [
+    "Puzzle inputs differ by user",
+    "Please don't repeatedly request this endpoint before it unlocks",
+    "404 Not Found",
+];
+
+pub fn validate(input: &str) -> Vec<InputIssue> {
+    if input.trim().is_empty() {
+        return vec![InputIssue::Empty];
+    }
+
+    let first_line = input.trim_start().lines().next().unwrap_or_default();
+    let start = first_line.to_ascii_lowercase();
+
+    if start.starts_with("<!doctype")
+        || start.starts_with("<html")
+        || ERROR_MESSAGES.iter().any(|m| input.contains(m))
+    {
+        return vec![InputIssue::ErrorPage(
+            first_line.trim().trim_end_matches('.').to_string(),
+        )];
+    }
+
+    let mut issues = vec![];
+
+    if input.contains("\r\n") {
+        issues.push(InputIssue::CrlfLineEndings);
+    }
+
+    if !input.ends_with('\n') {
+        issues.push(InputIssue::MissingTrailingNewline);
+    }
+
+    issues
+}
+
+/// A line describing every issue of the input of `id`, e.g. `Error: 2024-01: the input is empty.`.
+pub fn messages(issues: &[InputIssue], id: PuzzleId) -> Vec<String> {
+    issues
+        .iter()
+        .map(|issue| {
+            let level = if issue.is_fatal() { "Error" } else { "Warning" };
+            format!("{level}: {id}: {issue}")
+        })
+        .collect()
+}
+
+/// Print every issue of the input of `id`. Returns `false` if the input should not be solved.
+pub fn report(input: &str, id: PuzzleId) -> bool {
+    let issues = validate(input);
+
+    for message in messages(&issues, id) {
+        eprintln!("{message}");
+    }
+
+    !issues.iter().any(InputIssue::is_fatal)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{validate, InputIssue};
+
+    #[test]
+    fn accepts_valid_input() {
+        assert_eq!(validate("1 2\n3 4\n"), []);
+    }
+
+    #[test]
+    fn detects_empty_input() {
+        assert_eq!(validate(""), [InputIssue::Empty]);
+        assert_eq!(validate(" \n\n"), [InputIssue::Empty]);
+    }
+
+    #[test]
+    fn detects_error_pages() {
+        assert_eq!(
+            validate("<!DOCTYPE html>\n<html lang=\"en-us\">\n"),
+            [InputIssue::ErrorPage("<!DOCTYPE html>".into())]
+        );
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            [InputIssue::ErrorPage(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input".into()
+            )]
+        );
+        assert!(InputIssue::ErrorPage(String::new()).is_fatal());
+    }
+
+    #[test]
+    fn warns_about_line_endings() {
+        assert_eq!(
+            validate("1 2\r\n3 4"),
+            [
+                InputIssue::CrlfLineEndings,
+                InputIssue::MissingTrailingNewline
+            ]
+        );
+        assert!(!InputIssue::CrlfLineEndings.is_fatal());
+    }
+}
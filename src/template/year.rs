@@ -0,0 +1,211 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::Day;
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Environment variable holding the year commands default to if no `--year` is passed.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(2015..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        env::var(YEAR_ENV).ok()?.trim().parse().ok()
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Directory all data of this year is kept in, e.g. `data/2024`.
+    pub fn data_dir(self) -> String {
+        format!("data/{self}")
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a single puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let id = PuzzleId::new(Year::new(2024).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(id.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path of the solution binary, e.g. `./src/bin/2024-08.rs`.
+    pub fn bin_path(self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+
+    /// Path of a data file of this puzzle, e.g. `data/2024/inputs/08.txt`.
+    pub fn data_file(self, folder: &str) -> String {
+        format!("{}/{folder}/{}.txt", self.year.data_dir(), self.day)
+    }
+
+    /// Determines the id of a solution binary in a const context.
+    /// The year is taken from a `<year>-<day>` binary name and falls back to the `AOC_YEAR` the binary was compiled with.
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_bin_name(bin_name: &str, fallback_year: Option<&str>, day: Day) -> Self {
+        let year = match parse_year(bin_name.as_bytes()) {
+            Some(year) => year,
+            None => match fallback_year {
+                Some(year) => match parse_year(year.as_bytes()) {
+                    Some(year) => year,
+                    None => panic!("`AOC_YEAR` is not a valid year"),
+                },
+                None => panic!("could not determine the year of the solution, name the binary `<year>-<day>` or set `AOC_YEAR`"),
+            },
+        };
+        Self::new(Year(year), day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let year = Utc::now().with_timezone(&offset).year();
+        Some(Self::new(
+            Year::new(u16::try_from(year).ok()?)?,
+            Day::today()?,
+        ))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/// Parses the four leading digits of `bytes` into a year.
+const fn parse_year(bytes: &[u8]) -> Option<u16> {
+    if bytes.len() < 4 || (bytes.len() > 4 && bytes[4] != b'-') {
+        return None;
+    }
+
+    let mut year = 0;
+    let mut i = 0;
+    while i < 4 {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    if year < 2015 {
+        return None;
+    }
+    Some(year)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn determines_year_from_bin_name() {
+        let id = PuzzleId::__from_bin_name("2016-08", Some("2024"), day!(8));
+        assert_eq!(id, PuzzleId::new(Year(2016), day!(8)));
+
+        let id = PuzzleId::__from_bin_name("08", Some("2024"), day!(8));
+        assert_eq!(id.to_string(), "2024-08");
+        assert_eq!(id.data_file("inputs"), "data/2024/inputs/08.txt");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_without_year() {
+        PuzzleId::__from_bin_name("08", None, day!(8));
+    }
+}